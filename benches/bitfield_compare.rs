@@ -268,5 +268,83 @@ fn bench_unpack(c: &mut Criterion) {
   group.finish();
 }
 
-criterion_group!(benches, bench_pack, bench_unpack);
+const RECORDS: usize = 4096; // records per bulk iteration
+
+fn build_records() -> Vec<Header> {
+  (0..RECORDS)
+    .map(|i| Header { ver: (i % 32) as u8, kind: (i % 128) as u8, len: (i % 512) as u16, flags: (i % 32) as u8 })
+    .collect()
+}
+
+// Per-record `From` in a loop vs. the generated `pack_slice`/`unpack_slice`/`unpack_iter`. Each
+// baseline walks the buffer with the same `chunks_exact` loop shape as the bulk function it is
+// compared with, so the difference is the per-record codegen only.
+fn bench_slices(c: &mut Criterion) {
+  let mut group = c.benchmark_group("slices");
+  let records = build_records();
+  let mut bytes = vec![0u8; RECORDS * 4];
+  Header::pack_slice(&records, &mut bytes).unwrap();
+
+  group.throughput(Throughput::Bytes((RECORDS * 4) as u64));
+
+  group.bench_function(BenchmarkId::new("pack", "from-loop"), |b| {
+    let mut out = vec![0u8; RECORDS * 4];
+    b.iter(|| {
+      for (h, chunk) in black_box(&records).iter().zip(out.chunks_exact_mut(4)) {
+        chunk.copy_from_slice(&<[u8; 4]>::from(*h));
+      }
+      black_box(&out);
+    })
+  });
+
+  group.bench_function(BenchmarkId::new("pack", "pack_slice"), |b| {
+    let mut out = vec![0u8; RECORDS * 4];
+    b.iter(|| {
+      Header::pack_slice(black_box(&records), &mut out).unwrap();
+      black_box(&out);
+    })
+  });
+
+  group.bench_function(BenchmarkId::new("unpack", "from-loop"), |b| {
+    let mut out = vec![build_sample(); RECORDS];
+    b.iter(|| {
+      for (chunk, slot) in black_box(&bytes).chunks_exact(4).zip(out.iter_mut()) {
+        let mut record = [0u8; 4];
+        record.copy_from_slice(chunk);
+        *slot = Header::from(record);
+      }
+      black_box(&out);
+    })
+  });
+
+  group.bench_function(BenchmarkId::new("unpack", "unpack_slice"), |b| {
+    let mut out = vec![build_sample(); RECORDS];
+    b.iter(|| {
+      Header::unpack_slice(black_box(&bytes), &mut out).unwrap();
+      black_box(&out);
+    })
+  });
+
+  group.bench_function(BenchmarkId::new("unpack-fold", "from-loop"), |b| {
+    b.iter(|| {
+      let acc = black_box(&bytes).chunks_exact(4).fold(0u32, |acc, chunk| {
+        let mut record = [0u8; 4];
+        record.copy_from_slice(chunk);
+        acc.wrapping_add(Header::from(record).len as u32)
+      });
+      black_box(acc)
+    })
+  });
+
+  group.bench_function(BenchmarkId::new("unpack-fold", "unpack_iter"), |b| {
+    b.iter(|| {
+      let acc = Header::unpack_iter(black_box(&bytes)).fold(0u32, |acc, h| acc.wrapping_add(h.len as u32));
+      black_box(acc)
+    })
+  });
+
+  group.finish();
+}
+
+criterion_group!(benches, bench_pack, bench_unpack, bench_slices);
 criterion_main!(benches);
@@ -0,0 +1,113 @@
+// `dyn_layout!()`: emits a small no_std `DynLayout` interpreter into the calling crate.
+// A proc-macro crate cannot export runtime items, so the type is generated where it is used.
+// Its bit math mirrors `pack::generator::Chunk` so it agrees with the `#[pack]` conversions.
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+pub(super) fn expand(input: TokenStream2) -> TokenStream2 {
+  let vis = match syn::parse2::<syn::Visibility>(input) {
+    Ok(vis) => vis,
+    Err(e) => return e.to_compile_error(),
+  };
+  quote! {
+    /// Runtime interpreter for the `LAYOUT` metadata generated by `#[packbits::pack]`.
+    ///
+    /// Values are the raw field bits, sign-extended for signed fields; per-field transforms
+    /// (`invert`, `scale`, `fixed`, custom conversions, …) are not applied.
+    #[derive(Clone, Copy, Debug)]
+    #vis struct DynLayout {
+      fields: &'static [(&'static str, u32, u32, &'static str, bool)],
+      bytes: usize,
+      msb: bool,
+    }
+
+    impl DynLayout {
+      /// Interpret `LAYOUT`, `LAYOUT_BYTES` and `LAYOUT_MSB` of a packed type.
+      pub const fn new(fields: &'static [(&'static str, u32, u32, &'static str, bool)], bytes: usize, msb: bool) -> Self {
+        DynLayout { fields, bytes, msb }
+      }
+
+      /// The described fields as `(name, start_bit, width, kind, signed)`.
+      pub fn fields(&self) -> &'static [(&'static str, u32, u32, &'static str, bool)] {
+        self.fields
+      }
+
+      /// Size of the packed form in bytes.
+      pub fn byte_len(&self) -> usize {
+        self.bytes
+      }
+
+      /// Decode every field of `bytes` as `(name, value)` pairs, in declaration order.
+      pub fn decode<'a>(
+        &'a self,
+        bytes: &'a [u8],
+      ) -> core::result::Result<impl Iterator<Item = (&'static str, i128)> + 'a, &'static str> {
+        if bytes.len() != self.bytes {
+          return Err("pack: byte length does not match layout");
+        }
+        Ok(self.fields.iter().map(move |&(name, start, width, _, signed)| (name, self.read(bytes, start, width, signed))))
+      }
+
+      /// Decode a single field by name.
+      pub fn get(&self, bytes: &[u8], name: &str) -> core::result::Result<i128, &'static str> {
+        if bytes.len() != self.bytes {
+          return Err("pack: byte length does not match layout");
+        }
+        let &(_, start, width, _, signed) = self.field(name)?;
+        Ok(self.read(bytes, start, width, signed))
+      }
+
+      /// Encode `(name, value)` pairs into `out`, masking each value to its field's width.
+      /// Fields that are not listed keep their current bits.
+      pub fn encode(&self, values: &[(&str, i128)], out: &mut [u8]) -> core::result::Result<(), &'static str> {
+        if out.len() != self.bytes {
+          return Err("pack: byte length does not match layout");
+        }
+        for &(name, value) in values {
+          let &(_, start, width, _, _) = self.field(name)?;
+          self.write(out, start, width, value as u128);
+        }
+        Ok(())
+      }
+
+      fn field(&self, name: &str) -> core::result::Result<&'static (&'static str, u32, u32, &'static str, bool), &'static str> {
+        self.fields.iter().find(|f| f.0 == name).ok_or("pack: unknown field name")
+      }
+
+      // Walk the field in per-byte chunks: `take` bits at in-byte offset `off`, placed at
+      // `off` (lsb) or `8 - off - take` (msb), holding value bits `shift..shift + take`.
+      fn read(&self, bytes: &[u8], start: u32, width: u32, signed: bool) -> i128 {
+        let (mut pos, mut shift, mut raw) = (start as usize, 0u32, 0u128);
+        while shift < width {
+          let off = (pos % 8) as u32;
+          let take = (8 - off).min(width - shift);
+          let adj = if self.msb { 8 - off - take } else { off };
+          let mask = (1u32 << take) - 1;
+          raw |= (((bytes[pos / 8] as u32 >> adj) & mask) as u128) << shift;
+          pos += take as usize;
+          shift += take;
+        }
+        if signed && width < 128 {
+          let sh = 128 - width;
+          ((raw << sh) as i128) >> sh
+        } else {
+          raw as i128
+        }
+      }
+
+      fn write(&self, out: &mut [u8], start: u32, width: u32, value: u128) {
+        let (mut pos, mut shift) = (start as usize, 0u32);
+        while shift < width {
+          let off = (pos % 8) as u32;
+          let take = (8 - off).min(width - shift);
+          let adj = if self.msb { 8 - off - take } else { off };
+          let mask = ((1u32 << take) - 1) as u8;
+          let bits = (value >> shift) as u8 & mask;
+          out[pos / 8] = (out[pos / 8] & !(mask << adj)) | (bits << adj);
+          pos += take as usize;
+          shift += take;
+        }
+      }
+    }
+  }
+}
@@ -9,17 +9,92 @@
 //!   - Optional bit order per byte: add `msb` or `lsb` (default `lsb`).
 //! - Per-field directives:
 //!   - `#[bits(W)]` → width override (1..=128). If omitted and the field type is
-//!     one of {bool, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128}, its width is inferred
-//!     (bool=1, integer types use their full width). Otherwise `#[bits]` is required.
+//!     one of {bool, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, char} or a
+//!     `NonZero*` integer, its width is inferred (bool=1, char=32, others use their full width).
+//!     Otherwise `#[bits]` is required.
+//!   - `#[bits(W, none = V)]` on an `Option<T>` field → the raw value V encodes `None`; `T` is
+//!     packed as usual otherwise. Packing `Some` with a value equal to V fails with an error.
+//!   - `#[bits(W, fixed(frac = F))]` on an `f32`/`f64` field → stores the value as a W-bit
+//!     two's-complement integer scaled by 2^F (W ≤ 64). Add `unsigned` for an unsigned raw value,
+//!     `round = nearest|floor|ceil|trunc` (default `nearest`) and `overflow = saturate|error`
+//!     (default `saturate`; `error` makes packing `TryFrom`).
+//!   - `#[bits(W, scale = S, offset = O)]` on an integer field → value = raw * S + O on unpack and
+//!     (value - O) / S (rounded down, saturated to the raw range) on pack. The raw bits are unsigned
+//!     unless `signed` is given; `strict` rejects inexact or out-of-range values (making packing
+//!     `TryFrom`) instead of rounding and saturating.
+//!     The transformed range must fit the field's type, which is checked at compile time.
+//!   - `#[bits(W, bcd)]` / `#[bits(W, gray)]` on an unsigned integer field → stores the value as
+//!     packed BCD (one decimal digit per nibble) or Gray code. BCD makes both directions `TryFrom`:
+//!     packing rejects values with more digits than fit, unpacking rejects invalid digits.
+//!   - `#[bits(W, invert)]` → stores the bitwise complement of the value (active-low bools and
+//!     integers). A `none = V` sentinel always refers to the stored bits.
+//!   - `#[bits(W, reverse)]` → stores the value's W bits in reverse order (LSB-first serial
+//!     protocols), independent of the container's `msb`/`lsb` bit order.
+//!   - `#[bits(W, range = 0..=11)]` on an integer field → rejects values outside the range (also
+//!     `lo..hi`) when packing and unpacking, making both directions `TryFrom`.
 //!   - `#[skip(N)]` → reserves N bits immediately before the field.
-//! - Clean output: generated code uses straight-line byte ops (no runtime loops) and is no_std-friendly.
+//!   - `#[pack(ignore)]` / `#[pack(default = expr)]` → the field is not packed (local metadata);
+//!     unpacking fills it from `Default::default()` or `expr`.
+//! - `#[cfg(..)]` on fields is honored: bit positions are planned per configuration (a disabled
+//!   field takes no bits), and the conversions and layout diagram are emitted for each combination
+//!   of the field predicates (at most 4 distinct ones), gated with `#[cfg]`.
+//! - Field constants: every packed field gets `<FIELD>_BITS`, `<FIELD>_SHIFT` and `<FIELD>_MASK`
+//!   associated constants (`F0_…` for tuple fields). The mask is typed as the integer container (or
+//!   the smallest unsigned integer covering the bytes) and locates the field in
+//!   `from_le_bytes(packed)`, honoring the bit order; SHIFT is the field's least-significant bit.
+//!   SHIFT and MASK are omitted where `(raw & MASK) >> SHIFT` would not give the field's bits:
+//!   unaligned `msb` fields crossing a byte, and `reverse`/`invert`/`scale`/`offset`/`bcd`/`gray`.
+//! - Single-field access: `#[pack(bytes = N, accessors)]` adds `T::read_<field>(&[u8; N])` and
+//!   `T::write_<field>(&mut [u8; N], value)`, which decode or update just that field's bits
+//!   (`read_0`/`write_0` for tuple fields). Each returns a `Result` only when that field's
+//!   conversion in that direction can fail.
+//! - Bulk conversions: `T::pack_slice(&[T], &mut [u8])`, `T::unpack_slice(&[u8], &mut [T])` and
+//!   `T::unpack_iter(&[u8])` convert back-to-back records with tight `chunks_exact` loops, which
+//!   suits decoding large files. The slices must match in length (`items.len() * LAYOUT_BYTES`).
+//!   `pack_slice` reads each record by reference; custom field types (other than `with` fields)
+//!   must be `Clone` to call it.
+//! - Raw newtype: `#[pack(u32, raw = HeaderBits)]` also emits `#[repr(transparent)] struct
+//!   HeaderBits(pub u32)` (wrapping `[u8; N]` with `bytes = N`) with `ver()`, `set_ver()` and
+//!   `with_ver()` per field (`f0()`… for tuple fields) and conversions to and from the struct, for
+//!   register shadows. Not available on generic structs.
+//! - Borrowed views: `#[pack(bytes = N, views)]` also emits `HeaderRef<'a>` / `HeaderMut<'a>` over
+//!   `&'a [u8; N]` / `&'a mut [u8; N]` (also `TryFrom<&[u8]>` with a length check) with per-field
+//!   getters, plus `set_*` on the mutable view, to inspect and patch packets in place. Fields named
+//!   `new`, `as_bytes` or `view` clash with the views' own methods and are rejected.
+//! - Columnar decoding: `#[pack(u32, columns)]` also emits `HeaderColumns` with one `pub Vec` per
+//!   packed field (`f0`… for tuple fields). `HeaderColumns::decode(&[u8])` and `extend_from_bytes`
+//!   fill it from back-to-back records for numeric code that wants contiguous columns. It links
+//!   `alloc` itself and is not available on generic structs.
+//! - Volatile registers: `#[pack(u32, volatile)]` adds `unsafe fn read_volatile(ptr)`,
+//!   `write_volatile(ptr, value)`, `modify(ptr, |r| r.ver = 3)` and per-field `modify_ver(ptr,
+//!   value)`, which operate on a `*mut` container (the integer container, or `[u8; N]`) with
+//!   `read_volatile`/`write_volatile`.
+//! - Layout metadata: `LAYOUT` lists the packed fields as `(name, start_bit, width, kind, signed)`
+//!   tuples (kind is one of `bool`, `int`, `nonzero`, `float`, `char`, `fixed`, `custom`), next
+//!   to `LAYOUT_BYTES` and `LAYOUT_MSB`. Being a proc-macro crate, packbits cannot export a named
+//!   descriptor type, so only core types are used.
+//! - Runtime decoding: `packbits::dyn_layout!(pub);` defines a no_std `DynLayout` type in your
+//!   crate. `DynLayout::new(T::LAYOUT, T::LAYOUT_BYTES, T::LAYOUT_MSB)` then decodes `&[u8]` into
+//!   `(name, value)` pairs (raw bits, sign-extended for signed fields) and encodes `(name, value)`
+//!   pairs back, using the same bit math as the generated conversions.
+//! - Clean output: per-record conversions are straight-line byte ops (the only runtime loops are the
+//!   ones walking records in the bulk slice helpers and `<Name>Columns`) and are no_std-friendly.
 //! - Documentation candy: the macro appends an ASCII bit layout diagram into your struct’s docs.
+//!   Inverted fields are labeled with a `!` and listed below the diagram.
 //!
 //! Conversions
 //! - For structs with only primitive fields (bool/integers):
 //!   - `From<T> for [u8; N]` and `From<[u8; N]> for T` are generated (infallible).
+//! - Primitives are also recognized as `core::primitive::u16` / `std::primitive::bool`. A type alias
+//!   such as `type Reg = u16;` is opaque to the macro; declare it with `#[bits(W, int)]` (unsigned,
+//!   sized by W) or `#[bits(int = u16)]` (width inferred) to keep it primitive.
 //! - If any field is a custom type:
 //!   - Both directions use `TryFrom` instead, with `&'static str` errors.
+//! - `NonZeroU8`..`NonZeroI128` (also `NonZero<T>` and `core::num::` paths) are built in: packing
+//!   stays infallible, while unpacking becomes `TryFrom` and reports a zero value as an error.
+//! - `f32`/`f64` fields are stored as their raw bit pattern (`to_bits`/`from_bits`) and must use
+//!   their full width. `char` fields take 21..=32 bits (inferred: 32); unpacking becomes `TryFrom`
+//!   and rejects values that are not Unicode scalar values.
 //! - If an integer container form is used, e.g. `#[pack(u32)]`, matching `From`/`TryFrom` impls
 //!   are provided to and from that integer as well. Multi-byte loads/stores are little-endian.
 //!
@@ -31,6 +106,15 @@
 //! - Specify a width with `#[bits(W)]` and provide conversions to/from the minimal unsigned carrier
 //!   type large enough to hold W bits (`u8`, `u16`, …, up to `u128`). On read, the macro expects
 //!   `TryFrom<uN> for YourType`; on write, it expects `TryFrom<YourType> for uN`.
+//! - `#[bits(W, infallible)]` converts with `From<uN> for YourType` / `From<YourType> for uN` instead,
+//!   so the field does not make the struct's conversions fallible.
+//! - `#[bits(W, carrier = uN|iN)]` picks a different carrier, e.g. `carrier = u32` for a 9-bit type
+//!   that only converts from `u32`. Signed carriers sign-extend the raw bits on read.
+//! - Alternatively, `#[bits(W, with = path)]` calls `path::pack(&T) -> Result<uN, E>` and
+//!   `path::unpack(uN) -> Result<T, E>` instead (like serde's `with`), which also works for foreign
+//!   types where orphan rules rule out the `TryFrom` impls. Both directions become `TryFrom`.
+//! - Generic structs are supported: generics and where-clauses are carried over to every impl, and
+//!   fields whose type depends on a type parameter get those two bounds added automatically.
 //!
 //! Bit order and endianness
 //! - Bit order controls numbering within a byte: `lsb` (default) means bit 0 is least-significant;
@@ -39,14 +123,16 @@
 //!
 //! Limitations
 //! - Maximum per-field width is 128 bits.
-//! - The macro does not generate getters/setters or other mutation helpers—by design.
+//! - The macro does not generate getters/setters or other mutation helpers unless asked to with a
+//!   container option (`accessors`, `raw = Name`, `views`)—by design.
 //!
 //! Compile-time checks
 //! - Missing `#[bits(W)]` for non-primitive field types.
 //! - `#[bits(W)]` outside 1..=128.
 //! - `#[skip(N)]` must be > 0 and within bounds.
 //! - Not enough space for a field in the chosen container size.
-//! - Invalid attribute arguments (only `bytes = N`, `u8|u16|u32|u64|u128`, `msb|lsb` are accepted).
+//! - Invalid attribute arguments (only `bytes = N`, `u8|u16|u32|u64|u128`, `msb|lsb`, `raw = Name`,
+//!   `views`, `columns`, `accessors` and `volatile` are accepted).
 //!
 //! Example: keep your own API, get conversions for free
 //! ```
@@ -73,9 +159,17 @@
 extern crate proc_macro;
 use proc_macro::TokenStream;
 
+mod dyn_layout;
 mod pack;
 
 #[proc_macro_attribute]
 pub fn pack(args: TokenStream, input: TokenStream) -> TokenStream {
   pack::expand(args.into(), syn::parse_macro_input!(input as syn::ItemStruct)).into()
 }
+
+/// Define a no_std `DynLayout` type that decodes and encodes packed bytes at runtime from the
+/// `LAYOUT` metadata of any `#[pack]` type, e.g. `packbits::dyn_layout!(pub);`.
+#[proc_macro]
+pub fn dyn_layout(input: TokenStream) -> TokenStream {
+  dyn_layout::expand(input.into()).into()
+}
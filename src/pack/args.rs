@@ -31,29 +31,70 @@ pub(super) struct ContainerOpts {
   pub bytes_len: usize,
   pub int_ident: Option<Ident>,
   pub bit_order: BitOrder,
+  // Name of the optional `#[repr(transparent)]` newtype with per-field getters and setters.
+  pub raw: Option<Ident>,
+  // Emit `<Name>Ref<'a>` / `<Name>Mut<'a>` borrowed views over `[u8; N]`.
+  pub views: bool,
+  // Emit a `<Name>Columns` struct-of-arrays decoder.
+  pub columns: bool,
+  // Emit `read_<field>` / `write_<field>` single-field accessors on `[u8; N]`.
+  pub accessors: bool,
+  // Emit `read_volatile` / `write_volatile` / `modify` register helpers.
+  pub volatile: bool,
 }
 
-// Helper to parse comma-separated arguments supporting: bytes=N, u{8,16,32,64,128}, msb|lsb
+// Helper to parse comma-separated arguments supporting: bytes=N, u{8,16,32,64,128}, msb|lsb, raw=Name,
+// views, columns, accessors, volatile
 struct RawArgs {
   bytes: Option<usize>,
   int_ident: Option<(Ident, usize)>,
   bit_order: Option<BitOrder>,
+  raw: Option<Ident>,
+  views: bool,
+  columns: bool,
+  accessors: bool,
+  volatile: bool,
 }
 
 impl syn::parse::Parse for RawArgs {
   fn parse(input: syn::parse::ParseStream) -> SynResult<Self> {
     if input.is_empty() {
-      return Ok(Self { bytes: None, int_ident: None, bit_order: None });
+      return Ok(Self {
+        bytes: None,
+        int_ident: None,
+        bit_order: None,
+        raw: None,
+        views: false,
+        columns: false,
+        accessors: false,
+        volatile: false,
+      });
     }
     let mut bytes: Option<usize> = None;
     let mut int_ident: Option<(Ident, usize)> = None;
     let mut bit_order: Option<BitOrder> = None;
+    let mut raw: Option<Ident> = None;
+    let mut views = false;
+    let mut columns = false;
+    let mut accessors = false;
+    let mut volatile = false;
     while !input.is_empty() {
       let ident: Ident = input.parse()?;
       if ident == "bytes" {
         let _eq: Token![=] = input.parse()?;
         let lit: LitInt = input.parse()?;
         bytes = Some(lit.base10_parse::<usize>()?);
+      } else if ident == "raw" {
+        let _eq: Token![=] = input.parse()?;
+        raw = Some(input.parse()?);
+      } else if ident == "views" {
+        views = true;
+      } else if ident == "columns" {
+        columns = true;
+      } else if ident == "accessors" {
+        accessors = true;
+      } else if ident == "volatile" {
+        volatile = true;
       } else if ident == "msb" {
         bit_order = Some(BitOrder::Msb0);
       } else if ident == "lsb" {
@@ -69,7 +110,10 @@ impl syn::parse::Parse for RawArgs {
           _ => {
             return Err(syn::Error::new(
               ident.span(),
-              "expected `bytes = <int>`, integer type (u8/u16/u32/u64/u128), or `msb`/`lsb`",
+              concat!(
+                "expected `bytes = <int>`, integer type (u8/u16/u32/u64/u128), `msb`/`lsb`, `raw = Name`, ",
+                "`views`, `columns`, `accessors`, or `volatile`"
+              ),
             ));
           }
         };
@@ -78,7 +122,7 @@ impl syn::parse::Parse for RawArgs {
       // Optional trailing comma
       let _ = input.parse::<Token![,]>().ok();
     }
-    Ok(Self { bytes, int_ident, bit_order })
+    Ok(Self { bytes, int_ident, bit_order, raw, views, columns, accessors, volatile })
   }
 }
 
@@ -90,11 +134,16 @@ impl ContainerOpts {
         bytes_len: 1,
         int_ident: Some(Ident::new("u8", Span::call_site())),
         bit_order: BitOrder::Lsb0,
+        raw: None,
+        views: false,
+        columns: false,
+        accessors: false,
+        volatile: false,
       };
     }
 
     match syn::parse2::<RawArgs>(tokens) {
-      Ok(RawArgs { bytes, int_ident, bit_order }) => {
+      Ok(RawArgs { bytes, int_ident, bit_order, raw, views, columns, accessors, volatile }) => {
         // Validate combinations
         if bytes.is_some() && int_ident.is_some() {
           errors
@@ -106,11 +155,29 @@ impl ContainerOpts {
           (None, None) => (1, Some(Ident::new("u8", Span::call_site()))),
           (Some(n), Some((_id, _by))) => (n, None), // error above; ignore int container
         };
-        ContainerOpts { bytes_len, int_ident: int_ident_final, bit_order: bit_order.unwrap_or(BitOrder::Lsb0) }
+        ContainerOpts {
+          bytes_len,
+          int_ident: int_ident_final,
+          bit_order: bit_order.unwrap_or(BitOrder::Lsb0),
+          raw,
+          views,
+          columns,
+          accessors,
+          volatile,
+        }
       }
       Err(err) => {
         errors.push(err);
-        ContainerOpts { bytes_len: 1, int_ident: None, bit_order: BitOrder::Lsb0 }
+        ContainerOpts {
+          bytes_len: 1,
+          int_ident: None,
+          bit_order: BitOrder::Lsb0,
+          raw: None,
+          views: false,
+          columns: false,
+          accessors: false,
+          volatile: false,
+        }
       }
     }
   }
@@ -1,9 +1,211 @@
-use syn::{Attribute, Fields, ItemStruct, LitInt, Result, spanned::Spanned};
+use syn::{Attribute, Fields, Ident, ItemStruct, LitInt, Result, Token, parse::ParseStream, spanned::Spanned};
 
-// Parsed value for #[bits(W)]
-#[derive(Copy, Clone)]
+// Rounding applied when packing a fixed-point field.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(super) enum Rounding {
+  Nearest,
+  Floor,
+  Ceil,
+  Trunc,
+}
+
+// What happens when a value does not fit the field's raw range.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(super) enum Overflow {
+  Saturate,
+  Error,
+}
+
+// Parsed `fixed(frac = F, ..)`: a float stored as a raw integer scaled by 2^F.
+#[derive(Copy, Clone, Debug)]
+pub(super) struct Fixed {
+  pub frac: u32,
+  pub signed: bool,
+  pub round: Rounding,
+  pub overflow: Overflow,
+}
+
+// Parsed `scale = S` / `offset = O`: value = raw * S + O.
+#[derive(Copy, Clone, Default, Debug)]
+pub(super) struct Linear {
+  pub scale: Option<u64>,
+  pub offset: Option<i64>,
+  // Reject values that are not an exact step or fall outside the raw range.
+  pub strict: bool,
+  // Interpret the raw bits as two's complement instead of unsigned.
+  pub signed: bool,
+}
+
+impl Linear {
+  pub(super) fn scale(&self) -> i128 {
+    self.scale.unwrap_or(1) as i128
+  }
+  pub(super) fn offset(&self) -> i128 {
+    self.offset.unwrap_or(0) as i128
+  }
+}
+
+// Alternative integer encodings of a field's raw bits.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(super) enum Encoding {
+  // Packed binary-coded decimal, one digit per nibble.
+  Bcd,
+  // Reflected binary Gray code.
+  Gray,
+}
+
+// Parsed value for #[bits(W, options..)]; the width may be omitted when it can be inferred.
+#[derive(Clone, Default)]
 pub(super) struct Bits {
-  pub width: u16,
+  pub width: Option<u16>,
+  // Raw value that encodes `None` for an `Option<T>` field (`none = V`).
+  pub none: Option<u128>,
+  pub fixed: Option<Fixed>,
+  pub linear: Option<Linear>,
+  pub encoding: Option<Encoding>,
+  // Store the bitwise complement of the value (active-low signals).
+  pub invert: bool,
+  // Store the value's bits in reverse order within the field's width.
+  pub reverse: bool,
+  // Module providing `pack(&T) -> Result<uN, E>` and `unpack(uN) -> Result<T, E>`.
+  pub with: Option<syn::Path>,
+  // Integer type a custom field converts through instead of the minimal unsigned carrier.
+  pub carrier: Option<Ident>,
+  // Custom field converts with `From` both ways, keeping the conversions infallible.
+  pub infallible: bool,
+  // Treat the field type (e.g. an alias) as a primitive integer: `int` is unsigned and sized by
+  // the width, `int = iN` names the underlying type.
+  pub int: Option<Option<Ident>>,
+  // Legal values of an integer field (`range = lo..=hi` or `lo..hi`), as an inclusive range.
+  pub range: Option<(i128, i128)>,
+}
+
+impl Bits {
+  fn parse_option(&mut self, key: &Ident, input: ParseStream) -> Result<()> {
+    match key.to_string().as_str() {
+      "none" => {
+        input.parse::<Token![=]>()?;
+        self.none = Some(input.parse::<LitInt>()?.base10_parse::<u128>()?);
+      }
+      "fixed" => {
+        let content;
+        syn::parenthesized!(content in input);
+        self.fixed = Some(Fixed::parse(key, &content)?);
+      }
+      "scale" => {
+        input.parse::<Token![=]>()?;
+        let lit = input.parse::<LitInt>()?;
+        let v = lit.base10_parse::<u64>()?;
+        if v == 0 {
+          return Err(syn::Error::new(lit.span(), "`scale` must be > 0"));
+        }
+        self.linear.get_or_insert_default().scale = Some(v);
+      }
+      "offset" => {
+        input.parse::<Token![=]>()?;
+        let neg = input.parse::<Option<Token![-]>>()?.is_some();
+        let v = input.parse::<LitInt>()?.base10_parse::<i64>()?;
+        self.linear.get_or_insert_default().offset = Some(if neg { -v } else { v });
+      }
+      "bcd" => self.encoding = Some(Encoding::Bcd),
+      "gray" => self.encoding = Some(Encoding::Gray),
+      "with" => {
+        input.parse::<Token![=]>()?;
+        self.with = Some(input.parse::<syn::Path>()?);
+      }
+      "carrier" => {
+        input.parse::<Token![=]>()?;
+        self.carrier = Some(input.parse::<Ident>()?);
+      }
+      "infallible" => self.infallible = true,
+      "int" => {
+        let ty = if input.parse::<Option<Token![=]>>()?.is_some() {
+          Some(input.parse::<Ident>()?)
+        } else {
+          None
+        };
+        self.int = Some(ty);
+      }
+      "range" => {
+        input.parse::<Token![=]>()?;
+        let lo = Self::parse_signed(input)?;
+        let inclusive = input.parse::<Option<Token![..=]>>()?.is_some();
+        if !inclusive {
+          input.parse::<Token![..]>()?;
+        }
+        let hi_span = input.span();
+        let hi = Self::parse_signed(input)?;
+        let hi = if inclusive { Some(hi) } else { hi.checked_sub(1) };
+        match hi {
+          Some(hi) if lo <= hi => self.range = Some((lo, hi)),
+          _ => return Err(syn::Error::new(hi_span, "`range` must not be empty")),
+        }
+      }
+      "invert" => self.invert = true,
+      "reverse" => self.reverse = true,
+      "strict" => self.linear.get_or_insert_default().strict = true,
+      "signed" => self.linear.get_or_insert_default().signed = true,
+      _ => return Err(syn::Error::new(key.span(), format!("unknown `bits` option `{key}`"))),
+    }
+    Ok(())
+  }
+
+  // An integer literal with an optional leading `-`.
+  fn parse_signed(input: ParseStream) -> Result<i128> {
+    let neg = input.parse::<Option<Token![-]>>()?.is_some();
+    let v = input.parse::<LitInt>()?.base10_parse::<i128>()?;
+    Ok(if neg { -v } else { v })
+  }
+}
+
+impl Fixed {
+  // Parse `frac = F` plus optional `round = nearest|floor|ceil|trunc`,
+  // `overflow = saturate|error` and `unsigned`.
+  fn parse(key: &Ident, input: ParseStream) -> Result<Self> {
+    let mut frac = None;
+    let mut fixed = Fixed { frac: 0, signed: true, round: Rounding::Nearest, overflow: Overflow::Saturate };
+    while !input.is_empty() {
+      let opt: Ident = input.parse()?;
+      if opt == "unsigned" {
+        fixed.signed = false;
+      } else {
+        input.parse::<Token![=]>()?;
+        if opt == "frac" {
+          let lit = input.parse::<LitInt>()?;
+          let v = lit.base10_parse::<u32>()?;
+          if v > 127 {
+            return Err(syn::Error::new(lit.span(), "`frac` must be 0..=127"));
+          }
+          frac = Some(v);
+        } else {
+          let val: Ident = input.parse()?;
+          match (opt.to_string().as_str(), val.to_string().as_str()) {
+            ("round", "nearest") => fixed.round = Rounding::Nearest,
+            ("round", "floor") => fixed.round = Rounding::Floor,
+            ("round", "ceil") => fixed.round = Rounding::Ceil,
+            ("round", "trunc") => fixed.round = Rounding::Trunc,
+            ("overflow", "saturate") => fixed.overflow = Overflow::Saturate,
+            ("overflow", "error") => fixed.overflow = Overflow::Error,
+            _ => {
+              return Err(syn::Error::new(
+                val.span(),
+                "expected `round = nearest|floor|ceil|trunc` or `overflow = saturate|error`",
+              ));
+            }
+          }
+        }
+      }
+      let _ = input.parse::<Token![,]>().ok();
+    }
+    fixed.frac = frac.ok_or_else(|| syn::Error::new(key.span(), "`fixed` requires `frac = <int>`"))?;
+    Ok(fixed)
+  }
+}
+
+// Parsed field-level `#[pack(ignore)]` / `#[pack(default = expr)]`: the field is not packed and is
+// initialized from `expr` (or `Default::default()`) on unpack.
+pub(super) struct Ignore {
+  pub default: Option<syn::Expr>,
 }
 
 pub(super) struct Attrs;
@@ -13,16 +215,52 @@ impl Attrs {
     attrs.iter().find(|a| a.path().is_ident(ident))
   }
 
-  // Find and parse #[bits(W)] attribute on a field, validating the width range.
+  // Find and parse #[bits(W, options..)] attribute on a field, validating the width range.
   pub(super) fn parse_bits(attrs: &[Attribute]) -> Option<Result<Bits>> {
     Self::find_attr(attrs, "bits").map(|a| {
-      a.parse_args_with(|input: syn::parse::ParseStream| {
-        let width = input.parse::<LitInt>()?.base10_parse::<u32>()? as u16;
-        if !(1..=128).contains(&width) {
-          Err(syn::Error::new(a.span(), "bits width must be 1..=128"))
-        } else {
-          Ok(Bits { width })
+      a.parse_args_with(|input: ParseStream| {
+        let mut bits = Bits::default();
+        if input.peek(LitInt) {
+          let width = input.parse::<LitInt>()?.base10_parse::<u32>()? as u16;
+          if !(1..=128).contains(&width) {
+            return Err(syn::Error::new(a.span(), "bits width must be 1..=128"));
+          }
+          bits.width = Some(width);
+        }
+        let mut first = bits.width.is_none();
+        while !input.is_empty() {
+          if !first {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+              break;
+            }
+          }
+          first = false;
+          let key: Ident = input.parse()?;
+          bits.parse_option(&key, input)?;
+        }
+        Ok(bits)
+      })
+    })
+  }
+
+  // Find and parse #[pack(ignore)] / #[pack(default = expr)] on a field.
+  pub(super) fn parse_ignore(attrs: &[Attribute]) -> Option<Result<Ignore>> {
+    Self::find_attr(attrs, "pack").map(|a| {
+      a.parse_args_with(|input: ParseStream| {
+        let key: Ident = input.parse()?;
+        let ignore = match key.to_string().as_str() {
+          "ignore" => Ignore { default: None },
+          "default" => {
+            input.parse::<Token![=]>()?;
+            Ignore { default: Some(input.parse()?) }
+          }
+          _ => return Err(syn::Error::new(key.span(), "expected `ignore` or `default = expr`")),
+        };
+        if !input.is_empty() {
+          return Err(input.error("unexpected tokens after field `pack` option"));
         }
+        Ok(ignore)
       })
     })
   }
@@ -30,7 +268,7 @@ impl Attrs {
   // Find and parse #[skip(N)] attribute on a field; N must be > 0.
   pub(super) fn parse_skip(attrs: &[Attribute]) -> Option<Result<u32>> {
     Self::find_attr(attrs, "skip").map(|a| {
-      a.parse_args_with(|input: syn::parse::ParseStream| {
+      a.parse_args_with(|input: ParseStream| {
         let v = input.parse::<LitInt>()?.base10_parse::<u32>()?;
         if v == 0 {
           Err(syn::Error::new(a.span(), "`skip` must be > 0"))
@@ -43,7 +281,7 @@ impl Attrs {
 
   // Remove pack-related field attributes from the generated struct (doc clarity).
   fn is_pack_attr(a: &Attribute) -> bool {
-    a.path().is_ident("bits") || a.path().is_ident("skip")
+    a.path().is_ident("bits") || a.path().is_ident("skip") || a.path().is_ident("pack")
   }
 
   pub(super) fn strip_field_attrs(mut item: ItemStruct) -> ItemStruct {
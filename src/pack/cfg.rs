@@ -0,0 +1,84 @@
+// Conditionally compiled fields: a proc macro cannot evaluate `#[cfg(..)]`, so the struct is
+// planned once per combination of the distinct field predicates, and each plan's code is gated
+// on the matching `all(p1, not(p2), ..)` condition.
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Fields, ItemStruct};
+
+// Each predicate doubles the generated code; beyond this, split the struct instead.
+const MAX_PREDICATES: usize = 4;
+
+pub(super) struct CfgVariant {
+  // `None` when no field is conditional.
+  pub cond: Option<TokenStream>,
+  // The struct with the fields absent under `cond` removed.
+  pub item: ItemStruct,
+}
+
+// Predicates of a field's `#[cfg(..)]` attributes; the field exists when all of them hold.
+fn field_predicates(field: &syn::Field) -> syn::Result<Vec<TokenStream>> {
+  field
+    .attrs
+    .iter()
+    .filter(|a| a.path().is_ident("cfg"))
+    .map(|a| Ok(a.meta.require_list()?.tokens.clone()))
+    .collect()
+}
+
+pub(super) fn variants(item: &ItemStruct, errors: &mut Vec<syn::Error>) -> Vec<CfgVariant> {
+  let mut fields: Vec<(&syn::Field, Vec<String>)> = Vec::new();
+  let mut preds: Vec<TokenStream> = Vec::new();
+  for field in &item.fields {
+    match field_predicates(field) {
+      Ok(ps) => {
+        let keys = ps.iter().map(|p| p.to_string()).collect();
+        for p in ps {
+          if !preds.iter().any(|q| q.to_string() == p.to_string()) {
+            preds.push(p);
+          }
+        }
+        fields.push((field, keys));
+      }
+      Err(e) => errors.push(e),
+    }
+  }
+  if preds.is_empty() {
+    return vec![CfgVariant { cond: None, item: item.clone() }];
+  }
+  if preds.len() > MAX_PREDICATES {
+    let msg = format!("at most {MAX_PREDICATES} distinct `#[cfg]` predicates are supported on packed fields");
+    errors.push(syn::Error::new(item.ident.span(), msg));
+    return Vec::new();
+  }
+
+  let keys: Vec<String> = preds.iter().map(|p| p.to_string()).collect();
+  (0..1usize << preds.len())
+    .map(|mask| {
+      let holds = |key: &String| keys.iter().position(|k| k == key).is_some_and(|i| mask & (1 << i) != 0);
+      let terms = preds.iter().enumerate().map(|(i, p)| {
+        if mask & (1 << i) != 0 {
+          quote!(#p)
+        } else {
+          quote!(not(#p))
+        }
+      });
+      let mut item = item.clone();
+      let kept = fields
+        .iter()
+        .filter(|(_, ks)| ks.iter().all(holds))
+        .map(|(f, _)| (*f).clone());
+      match &mut item.fields {
+        Fields::Named(named) => named.named = kept.collect(),
+        Fields::Unnamed(unnamed) => unnamed.unnamed = kept.collect(),
+        Fields::Unit => {}
+      }
+      CfgVariant { cond: Some(quote!(all(#( #terms ),*))), item }
+    })
+    .collect()
+}
+
+// Compile errors that fire only under `cond`.
+pub(super) fn gated_errors(cond: &TokenStream, errors: Vec<syn::Error>) -> TokenStream {
+  let es = errors.into_iter().map(|e| e.to_compile_error());
+  quote! { #[cfg(#cond)] const _: () = { #( #es )* }; }
+}
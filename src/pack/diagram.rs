@@ -8,6 +8,9 @@
 //!
 //! This keeps the output stable for our tests while making the logic much
 //! easier to follow and maintain.
+//!
+//! Inverted (active-low) fields get a `!` in front of their width label and are
+//! listed in a short legend below the diagram, since 1-bit fields carry no label.
 
 use crate::pack::args::BitOrder;
 use crate::pack::layout::FieldSpec;
@@ -33,6 +36,8 @@ const GAP_WITHIN_CELL: usize = 1;
 const GAP_AT_BYTE_SEAM: usize = 3;
 // Padding added around the numeric label (e.g. " 24 ").
 const LABEL_PAD: &str = " ";
+// Prefix of the width label of inverted fields (e.g. " !8 ").
+const INVERT_MARK: &str = "!";
 
 // Label placement (row index and starting column) for a given field
 #[derive(Clone, Debug)]
@@ -185,7 +190,8 @@ impl Diagram {
   fn plan_labels(&self) -> Vec<Option<LabelPlacement>> {
     let mut plan = vec![None; self.fields.len()];
     for (fi, f) in self.fields.iter().enumerate() {
-      let label = format!("{}{}{}", LABEL_PAD, f.width, LABEL_PAD);
+      let mark = if f.bits.invert { INVERT_MARK } else { "" };
+      let label = format!("{}{}{}{}", LABEL_PAD, mark, f.width, LABEL_PAD);
       let lw = label.len();
       if (f.width as usize) < MIN_LABEL_BITS || (f.width as usize) < lw {
         continue;
@@ -198,7 +204,7 @@ impl Diagram {
           && seg.dash_end >= seg.dash_start
         {
           let len = seg.dash_end + 1 - seg.dash_start;
-          if best.as_ref().map(|&(_, s, e)| (e + 1 - s)).unwrap_or(0) < len {
+          if best.as_ref().map(|&(_, s, e)| e + 1 - s).unwrap_or(0) < len {
             best = Some((ri, seg.dash_start, seg.dash_end));
           }
         }
@@ -302,6 +308,16 @@ impl Diagram {
       self.render_row(&mut out, row_idx, &labels);
     }
     out.push_str(CODE_FENCE);
+    let inverted: Vec<String> = self
+      .fields
+      .iter()
+      .filter(|f| f.bits.invert)
+      .map(|f| format!("`{}`", f.name()))
+      .collect();
+    if !inverted.is_empty() {
+      out.push_str("\n\n_Inverted (stored as complement):_ ");
+      out.push_str(&inverted.join(", "));
+    }
     out
   }
 }
@@ -310,12 +326,27 @@ impl Diagram {
 mod tests {
   use super::Diagram;
   use crate::pack::args::BitOrder;
+  use crate::pack::attrs::Bits;
   use crate::pack::layout::{FieldKind, FieldSpec};
   use quote::format_ident;
   use syn::parse_quote;
 
   fn fs(name: &str, ty: syn::Type, width: u16, start_bit: usize) -> FieldSpec {
-    FieldSpec { ident: format_ident!("{}", name), ty: ty.clone(), width, start_bit, kind: FieldKind::from_type(&ty) }
+    let kind = FieldKind::from_type(&ty);
+    FieldSpec { ident: format_ident!("{}", name), ty, width, start_bit, kind, bits: Bits::default() }
+  }
+
+  #[test]
+  fn inverted_fields_are_marked() {
+    let mut ready = fs("ready", parse_quote!(bool), 1, 0);
+    ready.bits.invert = true;
+    let mut level = fs("level", parse_quote!(u8), 7, 1);
+    level.bits.invert = true;
+    let md = Diagram::new(1, BitOrder::Lsb0, &[ready, level]).render();
+    let (diagram, legend) = md.split_once("\n\n").expect("legend after the diagram");
+    let bl = bracket_lines(diagram);
+    assert!(bl[0].contains(" !7 "), "expected inverted label: {}", bl[0]);
+    assert_eq!(legend, "_Inverted (stored as complement):_ `ready`, `level`");
   }
 
   fn bracket_lines(md: &str) -> Vec<&str> {
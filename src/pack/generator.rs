@@ -1,9 +1,10 @@
-use crate::pack::args::BitOrder;
-use crate::pack::layout::{FieldKind, FieldSpec, Layout};
+use crate::pack::args::{BitOrder, ContainerOpts};
+use crate::pack::attrs::{Encoding, Fixed, Linear, Overflow, Rounding};
+use crate::pack::layout::{FieldKind, FieldSpec, IgnoredField, Layout, Member};
 
-use proc_macro2::{Ident, TokenStream};
-use quote::quote;
-use syn::ItemStruct;
+use proc_macro2::{Ident, Literal, TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote};
+use syn::{Attribute, Generics};
 
 #[derive(Clone, Copy, Debug)]
 pub(super) struct Chunk {
@@ -38,85 +39,943 @@ pub(crate) struct Generator<'a> {
   nbytes: usize,
   order: BitOrder,
   layout: &'a Layout,
-  cleaned: ItemStruct,
   int_ty: Option<TokenStream>,
+  // Optional raw newtype (`raw = Name`) wrapping the container.
+  raw: Option<&'a Ident>,
+  // Whether `<Name>Ref` / `<Name>Mut` views are emitted.
+  views: bool,
+  // Whether the `<Name>Columns` struct-of-arrays decoder is emitted.
+  columns: bool,
+  // Whether the `read_<field>` / `write_<field>` accessors are emitted.
+  accessors: bool,
+  // Whether the volatile register helpers are emitted.
+  volatile: bool,
+  // `cfg` predicate selecting this layout when fields are conditionally compiled.
+  cfg: Option<&'a TokenStream>,
+  // `Name<..>` as used in impl headers, the struct's own generics (for inherent impls), and
+  // those generics extended with the conversion bounds needed by generic custom fields.
+  self_ty: TokenStream,
+  struct_generics: &'a Generics,
+  generics: Generics,
 }
 
 impl<'a> Generator<'a> {
   pub(super) fn new(
     struct_name: &'a Ident,
-    nbytes: usize,
-    int_ident: Option<&'a syn::Ident>,
-    order: BitOrder,
+    opts: &'a ContainerOpts,
     layout: &'a Layout,
-    cleaned: ItemStruct,
+    struct_generics: &'a Generics,
+    cfg: Option<&'a TokenStream>,
   ) -> Self {
-    let int_ty = int_ident.map(|id| quote! { #id });
-    Self { struct_name, nbytes, order, layout, cleaned, int_ty }
+    let (nbytes, order, raw) = (opts.bytes_len, opts.bit_order, opts.raw.as_ref());
+    let (views, columns, accessors, volatile) = (opts.views, opts.columns, opts.accessors, opts.volatile);
+    let int_ty = opts.int_ident.as_ref().map(|id| quote! { #id });
+    let (_, ty_generics, _) = struct_generics.split_for_impl();
+    let self_ty = quote! { #struct_name #ty_generics };
+    let generics = Self::bounded_generics(struct_generics, layout);
+    Self {
+      struct_name,
+      nbytes,
+      order,
+      layout,
+      int_ty,
+      raw,
+      views,
+      columns,
+      accessors,
+      volatile,
+      cfg,
+      self_ty,
+      struct_generics,
+      generics,
+    }
+  }
+
+  // Whether a type mentions any of the struct's type parameters.
+  fn mentions_type_param(ty: &syn::Type, generics: &Generics) -> bool {
+    fn scan(ts: TokenStream, params: &[&Ident]) -> bool {
+      ts.into_iter().any(|tt| match tt {
+        TokenTree::Ident(id) => params.contains(&&id),
+        TokenTree::Group(g) => scan(g.stream(), params),
+        _ => false,
+      })
+    }
+    let params: Vec<&Ident> = generics.type_params().map(|p| &p.ident).collect();
+    !params.is_empty() && scan(ty.to_token_stream(), &params)
+  }
+
+  // Extend the struct's where-clause with `T: TryFrom<uN>` and `uN: TryFrom<T>` (`From` for
+  // `infallible` fields) for every custom field whose type depends on a type parameter, and with
+  // `T: Default` for such ignored fields initialized from `Default`.
+  fn bounded_generics(generics: &Generics, layout: &Layout) -> Generics {
+    let mut generics = generics.clone();
+    let bounds: Vec<syn::WherePredicate> = layout
+      .fields
+      .iter()
+      .filter(|f| f.kind == FieldKind::Custom && f.bits.with.is_none() && Self::mentions_type_param(&f.ty, &generics))
+      .flat_map(|f| {
+        let ty = &f.ty;
+        let u = Self::custom_carrier(f);
+        if f.bits.infallible {
+          [
+            syn::parse_quote!(#ty: core::convert::From<#u>),
+            syn::parse_quote!(#u: core::convert::From<#ty>),
+          ]
+        } else {
+          [
+            syn::parse_quote!(#ty: core::convert::TryFrom<#u>),
+            syn::parse_quote!(#u: core::convert::TryFrom<#ty>),
+          ]
+        }
+      })
+      .collect();
+    let defaults: Vec<syn::WherePredicate> = layout
+      .ignored
+      .iter()
+      .filter(|f| f.default.is_none() && Self::mentions_type_param(&f.ty, &generics))
+      .map(|f| {
+        let ty = &f.ty;
+        syn::parse_quote!(#ty: core::default::Default)
+      })
+      .collect();
+    generics
+      .make_where_clause()
+      .predicates
+      .extend(bounds.into_iter().chain(defaults));
+    generics
   }
 
-  fn append_layout_doc(&mut self) {
-    use syn::parse_quote;
-    self.cleaned.attrs.push(parse_quote!(#[doc = ""]));
-    self.cleaned.attrs.push(parse_quote!(#[doc = "_Bit layout_"]));
+  // Doc attributes carrying the layout diagram, gated on this layout's `cfg` predicate if any.
+  pub(super) fn layout_doc(&self) -> Vec<Attribute> {
     let md = super::diagram::Diagram::new(self.nbytes, self.order, &self.layout.fields).render();
-    self
-      .cleaned
-      .attrs
-      .extend(md.lines().map(|line| parse_quote!(#[doc = #line])));
+    ["", "_Bit layout_"]
+      .into_iter()
+      .chain(md.lines())
+      .map(|line| match self.cfg {
+        Some(cfg) => syn::parse_quote!(#[cfg_attr(#cfg, doc = #line)]),
+        None => syn::parse_quote!(#[doc = #line]),
+      })
+      .collect()
   }
 
   // Destructure an input struct value into local bindings matching field idents
-  // so subsequent code can reference them uniformly.
+  // so subsequent code can reference them uniformly. Ignored fields are not bound.
   fn destructure_bindings(&self) -> TokenStream {
     let struct_name = self.struct_name;
-    let fields = &self.layout.fields;
     if self.layout.is_tuple {
-      let ids: Vec<syn::Ident> = (0..fields.len())
-        .map(|i| syn::Ident::new(&format!("__t{}", i), proc_macro2::Span::call_site()))
-        .collect();
-      let locals = fields.iter().enumerate().map(|(i, f)| {
-        let n = &f.ident;
-        let s = syn::Ident::new(&format!("__t{}", i), proc_macro2::Span::call_site());
-        quote!( let #n = #s; )
+      let pat = self.layout.members().into_iter().map(|m| match m {
+        Member::Packed(f) => {
+          let id = &f.ident;
+          quote!( #id )
+        }
+        Member::Ignored(_) => quote!(_),
       });
-      quote!( let #struct_name( #( #ids ),* ) = value; #( #locals )* )
+      quote!( let #struct_name( #( #pat ),* ) = value; )
     } else {
-      let pat = fields.iter().map(|f| {
+      let pat = self.layout.fields.iter().map(|f| {
         let id = &f.ident;
         quote!( #id )
       });
-      quote!( let #struct_name { #( #pat ),* } = value; )
+      let rest = (!self.layout.ignored.is_empty()).then(|| quote!(..));
+      quote!( let #struct_name { #( #pat, )* #rest } = value; )
+    }
+  }
+
+  // Initializer of an ignored field on unpack.
+  fn ignored_init(f: &IgnoredField) -> TokenStream {
+    match &f.default {
+      Some(expr) => quote!(#expr),
+      None => quote!(core::default::Default::default()),
     }
   }
 
   // Build a plain struct construction expression (no Ok wrapping).
   fn struct_expr_from_bytes(&self) -> TokenStream {
-    let fields = &self.layout.fields;
     let struct_name = self.struct_name;
+    let members = self.layout.members();
     if self.layout.is_tuple {
-      let elems = fields.iter().map(|f| self.gen_from_bytes_expr(f));
+      let elems = members.into_iter().map(|m| match m {
+        Member::Packed(f) => self.gen_from_bytes_expr(f),
+        Member::Ignored(f) => Self::ignored_init(f),
+      });
       quote! { #struct_name( #( #elems ),* ) }
     } else {
-      let inits = fields.iter().map(|f| {
-        let n = &f.ident;
-        let e = self.gen_from_bytes_expr(f);
-        quote!( #n: #e )
+      let inits = members.into_iter().map(|m| match m {
+        Member::Packed(f) => {
+          let n = &f.ident;
+          let e = self.gen_from_bytes_expr(f);
+          quote!( #n: #e )
+        }
+        Member::Ignored(f) => {
+          let n = &f.ident;
+          let e = Self::ignored_init(f);
+          quote!( #n: #e )
+        }
       });
       quote! { Self { #( #inits, )* } }
     }
   }
 
-  // Implement either From or TryFrom depending on fallibility.
-  fn impl_conv(&self, from_ty: TokenStream, to_ty: TokenStream, body: TokenStream) -> TokenStream {
-    if self.layout.fallible {
-      quote! { impl core::convert::TryFrom<#from_ty> for #to_ty { type Error = &'static str; fn try_from(value: #from_ty) -> core::result::Result<Self, Self::Error> { #body } } }
+  // Inherent `<FIELD>_BITS`, `<FIELD>_SHIFT` and `<FIELD>_MASK` constants describing where each
+  // field sits in the container integer (`from_le_bytes` of the packed array). The mask is typed
+  // as the integer container, or the smallest unsigned integer covering the bytes; SHIFT is the
+  // position of the field's least-significant bit. SHIFT and MASK are only emitted when
+  // `(raw & MASK) >> SHIFT` yields the field's bits: they must be contiguous and in value order
+  // (not so for unaligned `msb` fields crossing a byte) and not re-encoded (`reverse`, `invert`,
+  // `scale`/`offset`, `bcd`/`gray`).
+  fn field_consts(&self) -> TokenStream {
+    let mask_ty = match &self.int_ty {
+      Some(int_ty) => Some(int_ty.clone()),
+      None if self.nbytes <= 16 => Some(Self::carriers((self.nbytes * 8).max(1) as u16).0),
+      None => None,
+    };
+    let consts = self.layout.fields.iter().map(|f| {
+      let name = f.name();
+      let prefix = match name.parse::<usize>() {
+        Ok(idx) => format!("F{idx}"),
+        Err(_) => name.trim_start_matches("r#").to_uppercase(),
+      };
+      let bits_id = format_ident!("{}_BITS", prefix);
+      let (width, bits_doc) = (f.width as u32, format!("Width of `{name}` in bits."));
+      let chunks = Chunk::for_field(f.width, f.start_bit);
+      let pos = |c: &Chunk| c.byte_idx as u32 * 8 + self.order.adjust_in_byte(c.bit_off, c.take) as u32;
+      let shift = chunks.iter().find(|c| c.src_shift == 0).map_or(0, pos);
+      let in_order = chunks.iter().all(|c| pos(c) == shift + c.src_shift as u32);
+      let extractable = (in_order && !f.transforms_raw()).then(|| {
+        let shift_id = format_ident!("{}_SHIFT", prefix);
+        let shift_doc = format!("Position of the least-significant bit of `{name}` in the container.");
+        let mask = mask_ty.as_ref().map(|ty| {
+          let mask = chunks
+            .iter()
+            .fold(0u128, |m, c| m | (((1u128 << c.take) - 1) << pos(c)));
+          let mask = Literal::u128_unsuffixed(mask);
+          let mask_id = format_ident!("{}_MASK", prefix);
+          let doc = format!("Bits of `{name}` in the container.");
+          quote! { #[doc = #doc] pub const #mask_id: #ty = #mask; }
+        });
+        quote! {
+          #[doc = #shift_doc] pub const #shift_id: u32 = #shift;
+          #mask
+        }
+      });
+      quote! {
+        #[doc = #bits_doc] pub const #bits_id: u32 = #width;
+        #extractable
+      }
+    });
+    quote! { #( #consts )* }
+  }
+
+  // `LAYOUT` (one `(name, start_bit, width, kind, signed)` tuple per packed field, in declaration
+  // order), `LAYOUT_BYTES` and `LAYOUT_MSB` for tools that introspect the layout at runtime.
+  fn layout_consts(&self) -> TokenStream {
+    let entries = self.layout.fields.iter().map(|f| {
+      let (name, start, width) = (f.name(), f.start_bit as u32, f.width as u32);
+      let (kind, signed) = (f.kind_name(), f.is_signed());
+      quote! { (#name, #start, #width, #kind, #signed) }
+    });
+    let (nbytes, msb) = (self.nbytes, self.order == BitOrder::Msb0);
+    quote! {
+      #[doc = "Packed fields as `(name, start_bit, width, kind, signed)`, in declaration order."]
+      pub const LAYOUT: &[(&str, u32, u32, &str, bool)] = &[#( #entries ),*];
+      #[doc = "Size of the packed form in bytes."]
+      pub const LAYOUT_BYTES: usize = #nbytes;
+      #[doc = "Whether bit 0 of each byte is its most-significant bit (`msb`)."]
+      pub const LAYOUT_MSB: bool = #msb;
+    }
+  }
+
+  // Inherent impl on the struct with the given generics, gated like the other impls.
+  fn inherent_impl(&self, generics: &Generics, items: TokenStream) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (struct_name, cfg) = (self.struct_name, self.cfg.map(|cfg| quote! { #[cfg(#cfg)] }));
+    quote! { #cfg impl #impl_generics #struct_name #ty_generics #where_clause { #items } }
+  }
+
+  // Type of a field as declared on the struct (`Option<T>` for `none` sentinel fields).
+  fn declared_ty(f: &FieldSpec) -> TokenStream {
+    let ty = &f.ty;
+    match f.bits.none {
+      Some(_) => quote! { core::option::Option<#ty> },
+      None => quote! { #ty },
+    }
+  }
+
+  // Decode `f` from a `bytes` array (or reference to one) in scope; the value is wrapped in `Ok`
+  // when unpacking the field can fail.
+  fn read_field_body(&self, f: &FieldSpec) -> TokenStream {
+    let (ty, value) = (Self::declared_ty(f), self.gen_from_bytes_expr(f));
+    if f.unpack_fallible() {
+      quote! { let value: #ty = #value; Ok(value) }
+    } else {
+      value
+    }
+  }
+
+  // Return type of a single-field read or write, a `Result` when that direction can fail.
+  fn field_result(fallible: bool, ty: TokenStream) -> TokenStream {
+    if fallible {
+      quote! { core::result::Result<#ty, &'static str> }
+    } else {
+      ty
+    }
+  }
+
+  // `read_<field>(&[u8; N])` / `write_<field>(&mut [u8; N], value)` touching only that field's
+  // bits, with `accessors`; each returns a `Result` when its direction of the field conversion
+  // can fail.
+  fn field_accessors(&self) -> TokenStream {
+    if !self.accessors {
+      return quote!();
+    }
+    let nbytes = self.nbytes;
+    let accessors = self.layout.fields.iter().map(|f| {
+      let name = f.name();
+      let name = name.trim_start_matches("r#");
+      let (read, write) = (format_ident!("read_{}", name), format_ident!("write_{}", name));
+      let (ident, ty) = (&f.ident, Self::declared_ty(f));
+      let (read_doc, write_doc) = (
+        format!("Read `{name}` directly from the packed bytes."),
+        format!("Write `{name}` into the packed bytes, leaving every other bit untouched."),
+      );
+      let read_ret = Self::field_result(f.unpack_fallible(), ty.clone());
+      let write_ret = f
+        .pack_fallible()
+        .then(|| quote!(-> core::result::Result<(), &'static str>));
+      let (read_body, stmt) = (self.read_field_body(f), self.gen_to_bytes_stmt(f));
+      let done = f.pack_fallible().then(|| quote!(Ok(())));
+      quote! {
+        #[doc = #read_doc]
+        pub fn #read(bytes: &[u8; #nbytes]) -> #read_ret { #read_body }
+        #[doc = #write_doc]
+        pub fn #write(bytes: &mut [u8; #nbytes], #ident: #ty) #write_ret { #stmt #done }
+      }
+    });
+    quote! { #( #accessors )* }
+  }
+
+  // Names used for a field on the generated wrappers: the getter (the field name, `fN` for tuple
+  // fields) and the base of `set_*`/`with_*`.
+  fn method_names(f: &FieldSpec) -> (Ident, String) {
+    let name = f.name();
+    let base = name.trim_start_matches("r#");
+    match name.parse::<usize>() {
+      Ok(idx) => (format_ident!("f{}", idx), format!("f{idx}")),
+      Err(_) => (f.ident.clone(), base.to_string()),
+    }
+  }
+
+  // Inner value of the raw newtype: the integer container, or else the byte array.
+  fn raw_inner(nbytes: usize, int_ty: Option<&Ident>) -> TokenStream {
+    match int_ty {
+      Some(int_ty) => quote! { #int_ty },
+      None => quote! { [u8; #nbytes] },
+    }
+  }
+
+  // `#[repr(transparent)] struct Raw(inner)` with conversions to and from the inner value. Emitted
+  // once per struct; the layout-dependent items come from `raw_impls`.
+  pub(super) fn raw_struct(struct_name: &Ident, vis: &syn::Visibility, opts: &ContainerOpts) -> TokenStream {
+    let Some(raw) = &opts.raw else { return quote!() };
+    let inner = Self::raw_inner(opts.bytes_len, opts.int_ident.as_ref());
+    let doc = format!("Raw packed form of [`{struct_name}`] with per-field getters and setters.");
+    quote! {
+      #[doc = #doc]
+      #[repr(transparent)]
+      #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+      #vis struct #raw(pub #inner);
+
+      impl core::convert::From<#inner> for #raw {
+        fn from(value: #inner) -> Self {
+          #raw(value)
+        }
+      }
+
+      impl core::convert::From<#raw> for #inner {
+        fn from(value: #raw) -> Self {
+          value.0
+        }
+      }
+    }
+  }
+
+  // Getters, `set_*` and `with_*` on the raw newtype (through the single-field accessors), and
+  // conversions between it and the struct.
+  fn raw_impls(&self) -> TokenStream {
+    let Some(raw) = self.raw else { return quote!() };
+    let struct_name = self.struct_name;
+    let (to_bytes, from_bytes) = match &self.int_ty {
+      Some(int_ty) => (quote! { self.0.to_le_bytes() }, quote! { <#int_ty>::from_le_bytes(bytes) }),
+      None => (quote! { self.0 }, quote! { bytes }),
+    };
+    let methods = self.layout.fields.iter().map(|f| {
+      let name = f.name();
+      let (getter, base) = Self::method_names(f);
+      let (set, with) = (format_ident!("set_{}", base), format_ident!("with_{}", base));
+      let (ident, ty) = (&f.ident, Self::declared_ty(f));
+      let get_ret = Self::field_result(f.unpack_fallible(), ty.clone());
+      let (read_body, stmt) = (self.read_field_body(f), self.gen_to_bytes_stmt(f));
+      let (docs_get, docs_set, docs_with) = (
+        format!("Value of `{name}`."),
+        format!("Replace `{name}`, leaving every other bit untouched."),
+        format!("Copy with `{name}` replaced."),
+      );
+      let setters = if f.pack_fallible() {
+        quote! {
+          #[doc = #docs_set]
+          pub fn #set(&mut self, #ident: #ty) -> core::result::Result<(), &'static str> {
+            let mut bytes = #to_bytes;
+            #stmt
+            self.0 = #from_bytes;
+            Ok(())
+          }
+          #[doc = #docs_with]
+          pub fn #with(mut self, value: #ty) -> core::result::Result<Self, &'static str> {
+            self.#set(value)?;
+            Ok(self)
+          }
+        }
+      } else {
+        quote! {
+          #[doc = #docs_set]
+          pub fn #set(&mut self, #ident: #ty) {
+            let mut bytes = #to_bytes;
+            #stmt
+            self.0 = #from_bytes;
+          }
+          #[doc = #docs_with]
+          #[must_use]
+          pub fn #with(mut self, value: #ty) -> Self {
+            self.#set(value);
+            self
+          }
+        }
+      };
+      quote! {
+        #[doc = #docs_get]
+        pub fn #getter(&self) -> #get_ret {
+          let bytes = #to_bytes;
+          #read_body
+        }
+        #setters
+      }
+    });
+    let cfg = self.cfg.map(|cfg| quote! { #[cfg(#cfg)] });
+    let mut out = quote! { #cfg impl #raw { #( #methods )* } };
+
+    let nbytes = self.nbytes;
+    let array_ty = quote! { [u8; #nbytes] };
+    let (pack_fallible, unpack_fallible) = (self.layout.pack_fallible, self.layout.unpack_fallible);
+    let to_raw_body = if pack_fallible {
+      quote! {
+        let bytes: #array_ty = <#array_ty as core::convert::TryFrom<#struct_name>>::try_from(value)?;
+        Ok(#raw(#from_bytes))
+      }
+    } else {
+      quote! {
+        let bytes: #array_ty = <#array_ty as core::convert::From<#struct_name>>::from(value);
+        #raw(#from_bytes)
+      }
+    };
+    out.extend(self.impl_conv(pack_fallible, quote! { #struct_name }, quote! { #raw }, to_raw_body));
+    let to_bytes = match &self.int_ty {
+      Some(_) => quote! { value.0.to_le_bytes() },
+      None => quote! { value.0 },
+    };
+    let from_raw_body = if unpack_fallible {
+      quote! { <#struct_name as core::convert::TryFrom<#array_ty>>::try_from(#to_bytes) }
+    } else {
+      quote! { <#struct_name as core::convert::From<#array_ty>>::from(#to_bytes) }
+    };
+    out.extend(self.impl_conv(unpack_fallible, quote! { #raw }, quote! { #struct_name }, from_raw_body));
+    out
+  }
+
+  // Methods the views define themselves, which a field getter of the same name would clash with.
+  const VIEW_METHODS: [&'static str; 3] = ["new", "as_bytes", "view"];
+
+  // Errors for packed fields whose view getter would clash with a fixed view method.
+  pub(super) fn view_name_errors(layout: &Layout) -> Vec<syn::Error> {
+    layout
+      .fields
+      .iter()
+      .filter(|f| Self::VIEW_METHODS.contains(&Self::method_names(f).0.to_string().as_str()))
+      .map(|f| {
+        let msg =
+          format!("field `{}` clashes with a method of the generated views; rename it or drop `views`", f.name());
+        syn::Error::new(f.ident.span(), msg)
+      })
+      .collect()
+  }
+
+  // `<Name>Ref<'a>` / `<Name>Mut<'a>` borrowing `[u8; N]`, with construction from slices. Emitted
+  // once per struct; the field getters and setters come from `view_impls`.
+  pub(super) fn view_structs(struct_name: &Ident, vis: &syn::Visibility, opts: &ContainerOpts) -> TokenStream {
+    if !opts.views {
+      return quote!();
+    }
+    let nbytes = opts.bytes_len;
+    let (view, view_mut) = (format_ident!("{}Ref", struct_name), format_ident!("{}Mut", struct_name));
+    let (doc, doc_mut) = (
+      format!("Read-only view of a packed [`{struct_name}`], decoding fields on access."),
+      format!("Mutable view of a packed [`{struct_name}`], decoding and updating fields in place."),
+    );
+    quote! {
+      #[doc = #doc]
+      #[derive(Clone, Copy, Debug)]
+      #vis struct #view<'a>(&'a [u8; #nbytes]);
+
+      impl<'a> #view<'a> {
+        #[doc = "Wrap packed bytes."]
+        pub fn new(bytes: &'a [u8; #nbytes]) -> Self {
+          #view(bytes)
+        }
+        #[doc = "The underlying packed bytes."]
+        pub fn as_bytes(&self) -> &'a [u8; #nbytes] {
+          self.0
+        }
+      }
+
+      impl<'a> core::convert::TryFrom<&'a [u8]> for #view<'a> {
+        type Error = &'static str;
+        fn try_from(bytes: &'a [u8]) -> core::result::Result<Self, Self::Error> {
+          <&'a [u8; #nbytes]>::try_from(bytes).map(#view).map_err(|_| "pack: slice length does not match")
+        }
+      }
+
+      #[doc = #doc_mut]
+      #[derive(Debug)]
+      #vis struct #view_mut<'a>(&'a mut [u8; #nbytes]);
+
+      impl<'a> #view_mut<'a> {
+        #[doc = "Wrap packed bytes."]
+        pub fn new(bytes: &'a mut [u8; #nbytes]) -> Self {
+          #view_mut(bytes)
+        }
+        #[doc = "The underlying packed bytes."]
+        pub fn as_bytes(&self) -> &[u8; #nbytes] {
+          self.0
+        }
+        #[doc = "Read-only view of the same bytes."]
+        pub fn view(&self) -> #view<'_> {
+          #view(self.0)
+        }
+      }
+
+      impl<'a> core::convert::TryFrom<&'a mut [u8]> for #view_mut<'a> {
+        type Error = &'static str;
+        fn try_from(bytes: &'a mut [u8]) -> core::result::Result<Self, Self::Error> {
+          <&'a mut [u8; #nbytes]>::try_from(bytes).map(#view_mut).map_err(|_| "pack: slice length does not match")
+        }
+      }
+    }
+  }
+
+  // Per-field getters on both views and setters on the mutable one, through the single-field
+  // accessors.
+  fn view_impls(&self) -> TokenStream {
+    if !self.views {
+      return quote!();
+    }
+    let (struct_name, nbytes) = (self.struct_name, self.nbytes);
+    let (view, view_mut) = (format_ident!("{}Ref", struct_name), format_ident!("{}Mut", struct_name));
+    let getters: Vec<TokenStream> = self
+      .layout
+      .fields
+      .iter()
+      .map(|f| {
+        let (getter, _) = Self::method_names(f);
+        let (ret, body) = (Self::field_result(f.unpack_fallible(), Self::declared_ty(f)), self.read_field_body(f));
+        let doc = format!("Value of `{}`.", f.name());
+        quote! {
+          #[doc = #doc]
+          pub fn #getter(&self) -> #ret {
+            let bytes: &[u8; #nbytes] = self.0;
+            #body
+          }
+        }
+      })
+      .collect();
+    let setters = self.layout.fields.iter().map(|f| {
+      let (_, base) = Self::method_names(f);
+      let set = format_ident!("set_{}", base);
+      let (ident, ty) = (&f.ident, Self::declared_ty(f));
+      let ret = f
+        .pack_fallible()
+        .then(|| quote!(-> core::result::Result<(), &'static str>));
+      let stmt = self.gen_to_bytes_stmt(f);
+      let done = f.pack_fallible().then(|| quote!(Ok(())));
+      let doc = format!("Replace `{}` in place, leaving every other bit untouched.", f.name());
+      quote! {
+        #[doc = #doc]
+        pub fn #set(&mut self, #ident: #ty) #ret {
+          let bytes: &mut [u8; #nbytes] = self.0;
+          #stmt
+          #done
+        }
+      }
+    });
+    let cfg = self.cfg.map(|cfg| quote! { #[cfg(#cfg)] });
+    quote! {
+      #cfg impl #view<'_> { #( #getters )* }
+      #cfg impl #view_mut<'_> { #( #getters )* #( #setters )* }
+    }
+  }
+
+  // Rebind a field borrowed from `&Self` as the owned value the packing statements expect:
+  // primitive values are copied, `with` fields stay borrowed (`path::pack` takes `&T`), and
+  // custom values are cloned. Returns the type that must be `Clone` in the latter case.
+  fn owned_field(f: &FieldSpec) -> (TokenStream, Option<TokenStream>) {
+    let id = &f.ident;
+    match f.kind {
+      FieldKind::Custom if f.bits.with.is_some() && f.bits.none.is_none() => (quote!(), None),
+      FieldKind::Custom => {
+        let ty = Self::declared_ty(f);
+        (quote! { let #id: #ty = core::clone::Clone::clone(#id); }, Some(ty))
+      }
+      _ => (quote! { let #id = *#id; }, None),
+    }
+  }
+
+  // Bulk conversions between `[Self]` and back-to-back packed records: `pack_slice`,
+  // `unpack_slice` and the `unpack_iter` adaptor. The loops run over `chunks_exact` with the
+  // lengths checked up front, so each iteration is the straight-line per-record code with no
+  // bounds checks. `pack_slice` reads the fields through `&Self`, so records are not cloned.
+  fn slice_fns(&self) -> TokenStream {
+    let nbytes = self.nbytes;
+    let array_ty = quote! { [u8; #nbytes] };
+    let unpack_fallible = self.layout.unpack_fallible;
+    let (owned, clone_tys): (Vec<_>, Vec<_>) = self.layout.fields.iter().map(Self::owned_field).unzip();
+    // Custom field types only need `Clone` where `pack_slice` is used; the higher-ranked form
+    // keeps the bound from being checked (and failing) at the definition for concrete types.
+    let clone_bounds = clone_tys
+      .into_iter()
+      .flatten()
+      .map(|ty| quote! { for<'__p> #ty: core::clone::Clone, });
+    let (destructure, stmts) = (
+      self.destructure_bindings(),
+      self
+        .layout
+        .fields
+        .iter()
+        .map(|f| self.gen_to_bytes_stmt(f))
+        .collect::<Vec<_>>(),
+    );
+    let unpack_one = if unpack_fallible {
+      quote! { <Self as core::convert::TryFrom<#array_ty>>::try_from(record) }
+    } else {
+      quote! { <Self as core::convert::From<#array_ty>>::from(record) }
+    };
+    let (unpack_store, item_ty) = if unpack_fallible {
+      (quote! { *slot = #unpack_one?; }, quote! { core::result::Result<Self, &'static str> })
+    } else {
+      (quote! { *slot = #unpack_one; }, quote! { Self })
+    };
+    // Zero-sized records (`bytes = 0`) take no bytes, so there is nothing to chunk (and
+    // `chunks_exact(0)` would panic): `pack_slice` writes nothing, `unpack_slice` fills every slot
+    // and `unpack_iter` cannot tell how many records there are, so it yields none.
+    if nbytes == 0 {
+      return quote! {
+        #[doc = "Pack `items` back to back into `out`; records of this type take no bytes, so `out` must be empty."]
+        pub fn pack_slice(items: &[Self], out: &mut [u8]) -> core::result::Result<(), &'static str> {
+          let _ = items;
+          if !out.is_empty() {
+            return Err("pack: slice length does not match");
+          }
+          Ok(())
+        }
+
+        #[doc = "Unpack one record into every slot of `out`; records of this type take no bytes, so `bytes` must be empty."]
+        pub fn unpack_slice(bytes: &[u8], out: &mut [Self]) -> core::result::Result<(), &'static str> {
+          if !bytes.is_empty() {
+            return Err("pack: slice length does not match");
+          }
+          for slot in out.iter_mut() {
+            let record: #array_ty = [];
+            #unpack_store
+          }
+          Ok(())
+        }
+
+        #[doc = "Iterate over the records packed back to back in `bytes`; records of this type take no bytes,"]
+        #[doc = "so there are none."]
+        pub fn unpack_iter(bytes: &[u8]) -> impl Iterator<Item = #item_ty> + '_ {
+          let _ = bytes;
+          core::iter::empty()
+        }
+      };
+    }
+    quote! {
+      #[doc = "Pack `items` back to back into `out`, which must be exactly `items.len() * LAYOUT_BYTES`"]
+      #[doc = "long."]
+      pub fn pack_slice(items: &[Self], out: &mut [u8]) -> core::result::Result<(), &'static str>
+      where
+        #( #clone_bounds )*
+      {
+        if out.len() != items.len() * #nbytes {
+          return Err("pack: slice length does not match");
+        }
+        for (value, chunk) in items.iter().zip(out.chunks_exact_mut(#nbytes)) {
+          // Scoped so field bindings cannot shadow `chunk`.
+          let record: #array_ty = {
+            let mut bytes: #array_ty = [0u8; #nbytes];
+            #destructure
+            #( #owned )*
+            #( #stmts )*
+            bytes
+          };
+          chunk.copy_from_slice(&record);
+        }
+        Ok(())
+      }
+
+      #[doc = "Unpack back-to-back records from `bytes` into `out`; `bytes` must be exactly `out.len() * LAYOUT_BYTES` long."]
+      pub fn unpack_slice(bytes: &[u8], out: &mut [Self]) -> core::result::Result<(), &'static str> {
+        if bytes.len() != out.len() * #nbytes {
+          return Err("pack: slice length does not match");
+        }
+        for (chunk, slot) in bytes.chunks_exact(#nbytes).zip(out.iter_mut()) {
+          let mut record: #array_ty = [0u8; #nbytes];
+          record.copy_from_slice(chunk);
+          #unpack_store
+        }
+        Ok(())
+      }
+
+      #[doc = "Iterate over the records packed back to back in `bytes`; a trailing partial record is ignored."]
+      pub fn unpack_iter(bytes: &[u8]) -> impl Iterator<Item = #item_ty> + '_ {
+        bytes.chunks_exact(#nbytes).map(|chunk| {
+          let mut record: #array_ty = [0u8; #nbytes];
+          record.copy_from_slice(chunk);
+          #unpack_one
+        })
+      }
+    }
+  }
+
+  // `<Name>Columns`: one `Vec` per packed field, filled from back-to-back records. It depends on
+  // the layout, so it is emitted (and cfg-gated) per configuration; `alloc` is linked under a
+  // per-struct alias so no `extern crate alloc;` is needed in the caller.
+  pub(super) fn columns(&self, vis: &syn::Visibility) -> TokenStream {
+    if !self.columns {
+      return quote!();
+    }
+    let (struct_name, nbytes) = (self.struct_name, self.nbytes);
+    let columns = format_ident!("{}Columns", struct_name);
+    let alloc = format_ident!("__packbits_alloc_{}", struct_name);
+    let vec = quote! { #alloc::vec::Vec };
+    let cfg = self.cfg.map(|cfg| quote! { #[cfg(#cfg)] });
+    let fields = &self.layout.fields;
+    let names: Vec<Ident> = fields.iter().map(|f| Self::method_names(f).0).collect();
+    let tys: Vec<TokenStream> = fields.iter().map(Self::declared_ty).collect();
+    let docs = fields
+      .iter()
+      .map(|f| format!("Values of `{}`, one per record.", f.name()));
+    let values = fields.iter().map(|f| self.gen_from_bytes_expr(f));
+    // Positional locals, so a field named like a local (`bytes`, `chunk`) cannot shadow it.
+    let locals: Vec<Ident> = (0..fields.len()).map(|i| format_ident!("__value{}", i)).collect();
+    let doc = format!("Struct-of-arrays form of packed [`{struct_name}`] records, one column per field.");
+    let len = match names.first() {
+      Some(first) => quote! { self.#first.len() },
+      None => quote! { self.records },
+    };
+    // A struct without packed fields still counts its records.
+    let (records_field, records_init, records_push) = if names.is_empty() {
+      (quote! { records: usize, }, quote! { records: 0, }, quote! { self.records += 1; })
     } else {
-      quote! { impl core::convert::From<#from_ty> for #to_ty { fn from(value: #from_ty) -> Self { #body } } }
+      (quote!(), quote!(), quote!())
+    };
+    // Zero-sized records hold no data: only an empty buffer decodes, and it decodes to no records.
+    let (capacity, walk) = if nbytes == 0 {
+      (
+        quote! { 0 },
+        quote! {
+          if !bytes.is_empty() {
+            return Err("pack: slice length does not match");
+          }
+        },
+      )
+    } else {
+      (
+        quote! { bytes.len() / #nbytes },
+        quote! {
+          let chunks = bytes.chunks_exact(#nbytes);
+          if !chunks.remainder().is_empty() {
+            return Err("pack: slice length does not match");
+          }
+          for chunk in chunks {
+            let mut bytes: [u8; #nbytes] = [0u8; #nbytes];
+            bytes.copy_from_slice(chunk);
+            #( let #locals: #tys = #values; )*
+            #( self.#names.push(#locals); )*
+            #records_push
+          }
+        },
+      )
+    };
+    quote! {
+      #cfg
+      extern crate alloc as #alloc;
+
+      #cfg
+      #[doc = #doc]
+      #vis struct #columns {
+        #( #[doc = #docs] pub #names: #vec<#tys>, )*
+        #records_field
+      }
+
+      #cfg
+      impl core::default::Default for #columns {
+        fn default() -> Self {
+          #columns { #( #names: #vec::new(), )* #records_init }
+        }
+      }
+
+      #cfg
+      impl #columns {
+        #[doc = "Empty columns with room for `records` records each."]
+        pub fn with_capacity(records: usize) -> Self {
+          #columns { #( #names: #vec::with_capacity(records), )* #records_init }
+        }
+
+        #[doc = "Decode back-to-back records; `bytes` must hold a whole number of records."]
+        pub fn decode(bytes: &[u8]) -> core::result::Result<Self, &'static str> {
+          let mut columns = Self::with_capacity(#capacity);
+          columns.extend_from_bytes(bytes)?;
+          Ok(columns)
+        }
+
+        #[doc = "Append back-to-back records to the columns. A record that fails to decode stops the"]
+        #[doc = "walk; the columns keep every record before it."]
+        pub fn extend_from_bytes(&mut self, bytes: &[u8]) -> core::result::Result<(), &'static str> {
+          #walk
+          Ok(())
+        }
+
+        #[doc = "Number of decoded records."]
+        pub fn len(&self) -> usize {
+          #len
+        }
+
+        #[doc = "Whether no records have been decoded."]
+        pub fn is_empty(&self) -> bool {
+          self.len() == 0
+        }
+      }
     }
   }
 
-  fn carriers(&self, width: u16) -> (TokenStream, TokenStream, TokenStream) {
+  // Volatile register helpers over a `*mut` container (integer or byte array), with `volatile`:
+  // whole-value `read_volatile`/`write_volatile`/`modify` and per-field `modify_<field>`.
+  fn volatile_fns(&self) -> TokenStream {
+    if !self.volatile {
+      return quote!();
+    }
+    let (nbytes, struct_name) = (self.nbytes, &self.self_ty);
+    let array_ty = quote! { [u8; #nbytes] };
+    let (reg, load, store) = match &self.int_ty {
+      Some(int_ty) => (int_ty.clone(), quote! { reg.to_le_bytes() }, quote! { <#int_ty>::from_le_bytes(bytes) }),
+      None => (array_ty.clone(), quote! { reg }, quote! { bytes }),
+    };
+    let result = |fallible: bool, ty: TokenStream| {
+      if fallible {
+        quote! { core::result::Result<#ty, &'static str> }
+      } else {
+        ty
+      }
+    };
+    let try_op = |fallible: bool| if fallible { quote!(?) } else { quote!() };
+    let ok = |fallible: bool| if fallible { quote!(Ok(())) } else { quote!() };
+    let (pack_fallible, unpack_fallible) = (self.layout.pack_fallible, self.layout.unpack_fallible);
+    let to_bytes = if pack_fallible {
+      quote! { <#array_ty as core::convert::TryFrom<#struct_name>>::try_from(value)? }
+    } else {
+      quote! { <#array_ty as core::convert::From<#struct_name>>::from(value) }
+    };
+    let from_bytes = if unpack_fallible {
+      quote! { <#struct_name as core::convert::TryFrom<#array_ty>>::try_from(bytes) }
+    } else {
+      quote! { <#struct_name as core::convert::From<#array_ty>>::from(bytes) }
+    };
+    let safety = "# Safety\n\n`ptr` must be valid for volatile reads and writes and properly aligned.";
+    let fields = self.layout.fields.iter().map(|f| {
+      let (_, base) = Self::method_names(f);
+      let modify = format_ident!("modify_{}", base);
+      let (ident, ty, stmt) = (&f.ident, Self::declared_ty(f), self.gen_to_bytes_stmt(f));
+      let (ret, done) = (result(f.pack_fallible(), quote!(())), ok(f.pack_fallible()));
+      let doc = format!("Read-modify-write only `{}` of the register at `ptr`.\n\n{safety}", f.name());
+      quote! {
+        #[doc = #doc]
+        pub unsafe fn #modify(ptr: *mut #reg, #ident: #ty) -> #ret {
+          // Scoped so the register cannot shadow a field argument of the same name.
+          let mut bytes: #array_ty = {
+            let reg = unsafe { ptr.read_volatile() };
+            #load
+          };
+          #stmt
+          unsafe { ptr.write_volatile(#store) };
+          #done
+        }
+      }
+    });
+    let (read_doc, write_doc, modify_doc) = (
+      format!("Volatile load of the register at `ptr`, unpacked.\n\n{safety}"),
+      format!("Pack `value` and store it to the register at `ptr` with a volatile write.\n\n{safety}"),
+      format!("Read-modify-write the register at `ptr` through `f`.\n\n{safety}"),
+    );
+    let (read_ret, write_ret) = (result(unpack_fallible, quote!(Self)), result(pack_fallible, quote!(())));
+    let modify_ret = result(pack_fallible || unpack_fallible, quote!(()));
+    let (try_read, try_write, modify_ok) =
+      (try_op(unpack_fallible), try_op(pack_fallible), ok(pack_fallible || unpack_fallible));
+    let write_ok = ok(pack_fallible);
+    let items = quote! {
+      #[doc = #read_doc]
+      pub unsafe fn read_volatile(ptr: *const #reg) -> #read_ret {
+        let reg = unsafe { ptr.read_volatile() };
+        let bytes: #array_ty = #load;
+        #from_bytes
+      }
+      #[doc = #write_doc]
+      pub unsafe fn write_volatile(ptr: *mut #reg, value: Self) -> #write_ret {
+        let bytes: #array_ty = #to_bytes;
+        unsafe { ptr.write_volatile(#store) };
+        #write_ok
+      }
+      #[doc = #modify_doc]
+      pub unsafe fn modify(ptr: *mut #reg, f: impl FnOnce(&mut Self)) -> #modify_ret {
+        let mut value = unsafe { Self::read_volatile(ptr) } #try_read;
+        f(&mut value);
+        unsafe { Self::write_volatile(ptr, value) } #try_write;
+        #modify_ok
+      }
+      #( #fields )*
+    };
+    self.inherent_impl(&self.generics, items)
+  }
+
+  // Implement either From or TryFrom depending on the direction's fallibility.
+  fn impl_conv(&self, fallible: bool, from_ty: TokenStream, to_ty: TokenStream, body: TokenStream) -> TokenStream {
+    let (impl_generics, _, where_clause) = self.generics.split_for_impl();
+    let cfg = self.cfg.map(|cfg| quote! { #[cfg(#cfg)] });
+    if fallible {
+      quote! { #cfg impl #impl_generics core::convert::TryFrom<#from_ty> for #to_ty #where_clause { type Error = &'static str; fn try_from(value: #from_ty) -> core::result::Result<Self, Self::Error> { #body } } }
+    } else {
+      quote! { #cfg impl #impl_generics core::convert::From<#from_ty> for #to_ty #where_clause { fn from(value: #from_ty) -> Self { #body } } }
+    }
+  }
+
+  // Bit width of the minimal carrier chosen by `carriers`.
+  fn carrier_bits(width: u16) -> u32 {
+    match width {
+      1..=8 => 8,
+      9..=16 => 16,
+      17..=32 => 32,
+      33..=64 => 64,
+      _ => 128,
+    }
+  }
+
+  fn carriers(width: u16) -> (TokenStream, TokenStream, TokenStream) {
     let (u_ty, i_ty) = match width {
       1..=8 => (quote!(u8), quote!(i8)),
       9..=16 => (quote!(u16), quote!(i16)),
@@ -138,13 +997,18 @@ impl<'a> Generator<'a> {
     (u_ty, i_ty, mask)
   }
 
+  // Convert a custom field value through `TryFrom`, or `From` when marked `infallible`.
   fn custom_try_from(
     &self,
-    ty: &syn::Type,
+    f: &FieldSpec,
     from_ty: &TokenStream,
     to_ty: &TokenStream,
     value_expr: &TokenStream,
   ) -> TokenStream {
+    if f.bits.infallible {
+      return quote! { <#to_ty as core::convert::From<#from_ty>>::from(#value_expr) };
+    }
+    let ty = &f.ty;
     let fname = format!("{}", quote::quote!(#ty));
     quote! {
       <#to_ty as core::convert::TryFrom<#from_ty>>::try_from(#value_expr)
@@ -152,14 +1016,24 @@ impl<'a> Generator<'a> {
     }
   }
 
+  // Call `<with>::pack(&value)` or `<with>::unpack(raw)` for a `with = path` field.
+  fn with_call(&self, f: &FieldSpec, path: &syn::Path, func: &str, arg: &TokenStream) -> TokenStream {
+    let func = Ident::new(func, proc_macro2::Span::call_site());
+    let ty = &f.ty;
+    let fname = format!("{}", quote::quote!(#ty));
+    quote! {
+      #path::#func(#arg).map_err(|_| concat!("pack: field conversion failed: ", #fname))?
+    }
+  }
+
   fn signed_conversion(&self, raw: &TokenStream, width: u32, target_bits: u32) -> TokenStream {
     if width == target_bits {
-      let (raw_ty, signed_ty, _) = self.carriers(target_bits as u16);
+      let (raw_ty, signed_ty, _) = Self::carriers(target_bits as u16);
       quote! {{ let raw: #raw_ty = #raw; raw as #signed_ty }}
     } else {
       let carrier_bits = if target_bits <= 32 { 32 } else { target_bits };
       let shift = carrier_bits.saturating_sub(width);
-      let (carrier_ty, signed_carrier_ty, _) = self.carriers(carrier_bits as u16);
+      let (carrier_ty, signed_carrier_ty, _) = Self::carriers(carrier_bits as u16);
       quote! {{
         let raw: #carrier_ty = (#raw) as #carrier_ty;
         ((raw << #shift) as #signed_carrier_ty >> #shift) as #carrier_ty
@@ -167,8 +1041,36 @@ impl<'a> Generator<'a> {
     }
   }
 
+  // Field bits as read from the bytes, with `reverse` and `invert` undone.
   fn raw_expr_for_field(&self, f: &FieldSpec) -> TokenStream {
-    let (u, _i, _mask) = self.carriers(f.width);
+    let stored = self.stored_expr_for_field(f);
+    let raw = if f.bits.reverse {
+      Self::reverse_within(f.width, &stored)
+    } else {
+      stored
+    };
+    if f.bits.invert {
+      let (_u, _i, mask) = Self::carriers(f.width);
+      quote! { (!(#raw) & #mask) }
+    } else {
+      raw
+    }
+  }
+
+  // Reverse the low `width` bits of a carrier-typed value.
+  fn reverse_within(width: u16, value: &TokenStream) -> TokenStream {
+    let (u, _, _) = Self::carriers(width);
+    let sh = Self::carrier_bits(width) - width as u32;
+    if sh == 0 {
+      quote! { <#u>::reverse_bits(#value) }
+    } else {
+      quote! { (<#u>::reverse_bits(#value) >> #sh) }
+    }
+  }
+
+  // Field bits exactly as stored in the bytes.
+  fn stored_expr_for_field(&self, f: &FieldSpec) -> TokenStream {
+    let (u, _i, _mask) = Self::carriers(f.width);
     let terms = Chunk::for_field(f.width, f.start_bit).into_iter().map(|c| {
       let i = c.byte_idx;
       let take = c.take as u32;
@@ -195,35 +1097,316 @@ impl<'a> Generator<'a> {
   }
 
   fn gen_from_bytes_expr(&self, f: &FieldSpec) -> TokenStream {
+    let value = self.value_from_bytes_expr(f);
+    let value = match f.bits.range {
+      Some(_) => {
+        let ty = &f.ty;
+        let check = Self::range_check(f, &quote!(value));
+        quote!({ let value: #ty = #value; #check value })
+      }
+      None => value,
+    };
+    match f.bits.none {
+      Some(none) => {
+        let raw = self.stored_expr_for_field(f);
+        let none = Literal::u128_unsuffixed(none);
+        quote! { if (#raw) == #none { None } else { Some(#value) } }
+      }
+      None => value,
+    }
+  }
+
+  fn value_from_bytes_expr(&self, f: &FieldSpec) -> TokenStream {
     let ty = &f.ty;
     let raw = self.raw_expr_for_field(f);
-    let (u, _i, _mask_unused) = self.carriers(f.width);
+    let (u, _i, _mask_unused) = Self::carriers(f.width);
+    match f.kind {
+      FieldKind::Bool => quote!(#raw != 0),
+      FieldKind::Int { .. } => match (f.bits.linear, f.bits.encoding) {
+        (Some(linear), _) => self.linear_from_raw(f, &linear, &raw),
+        (None, Some(encoding)) => self.decode_raw(f, encoding, &raw),
+        (None, None) => self.int_from_bytes_expr(f, &raw, &quote!(#ty)),
+      },
+      FieldKind::NonZero { signed, bytes } => {
+        let prim = Self::int_type(signed, bytes);
+        let v = self.int_from_bytes_expr(f, &raw, &prim);
+        let fname = f.name();
+        quote! {
+          <#ty>::new(#v).ok_or(concat!("pack: zero value in non-zero field: ", #fname))?
+        }
+      }
+      FieldKind::Float { bytes } => {
+        let bits = Self::int_type(false, bytes);
+        let v = self.int_from_bytes_expr(f, &raw, &bits);
+        quote! { <#ty>::from_bits(#v) }
+      }
+      FieldKind::Char => {
+        let fname = f.name();
+        quote! {
+          core::char::from_u32(#raw as u32).ok_or(concat!("pack: invalid char in field: ", #fname))?
+        }
+      }
+      FieldKind::Fixed { .. } => self.fixed_from_raw(f, &raw),
+      FieldKind::Custom => {
+        let carrier = Self::custom_carrier(f);
+        let raw = match f.carrier() {
+          // `signed_conversion` already yields the signed type when the width matches.
+          Some((true, bytes)) if f.width as u32 == bytes as u32 * 8 => {
+            self.signed_conversion(&raw, f.width as u32, bytes as u32 * 8)
+          }
+          Some((true, bytes)) => {
+            let ext = self.signed_conversion(&raw, f.width as u32, bytes as u32 * 8);
+            quote!((#ext) as #carrier)
+          }
+          Some((false, bytes)) if bytes as u32 * 8 != Self::carrier_bits(f.width) => quote!((#raw) as #carrier),
+          _ => quote!(#raw as #u),
+        };
+        match &f.bits.with {
+          Some(path) => self.with_call(f, path, "unpack", &raw),
+          None => self.custom_try_from(f, &carrier, &quote!(#ty), &raw),
+        }
+      }
+    }
+  }
+
+  // Reject values outside the field's declared `range`.
+  fn range_check(f: &FieldSpec, value: &TokenStream) -> TokenStream {
+    let Some((lo, hi)) = f.bits.range else { return quote!() };
+    let (lo, hi) = (Literal::i128_unsuffixed(lo), Literal::i128_unsuffixed(hi));
+    let fname = f.name();
+    quote! {
+      if !(#lo..=#hi).contains(&#value) {
+        return Err(concat!("pack: value out of range in field: ", #fname));
+      }
+    }
+  }
+
+  // Integer type a custom field converts through: `carrier = ..` or the minimal unsigned carrier.
+  fn custom_carrier(f: &FieldSpec) -> TokenStream {
+    match &f.bits.carrier {
+      Some(carrier) => quote!(#carrier),
+      None => Self::carriers(f.width).0,
+    }
+  }
+
+  // Raw integer → `raw * scale + offset`; the layout checked that the result fits the type.
+  fn linear_from_raw(&self, f: &FieldSpec, linear: &Linear, raw: &TokenStream) -> TokenStream {
+    let ty = &f.ty;
+    let (scale, offset) = (Literal::i128_unsuffixed(linear.scale()), Literal::i128_unsuffixed(linear.offset()));
+    let raw = if linear.signed {
+      let ext = self.signed_conversion(raw, f.width as u32, 64);
+      quote! { ((#ext) as i64) as i128 }
+    } else {
+      quote! { (#raw) as i128 }
+    };
+    quote!({ (#raw * #scale + #offset) as #ty })
+  }
+
+  // Value → `(value - offset) / scale`, rounded down. Evaluates to an `i128`; with `strict`,
+  // inexact or out-of-range values are rejected instead of being masked.
+  fn linear_to_raw(&self, f: &FieldSpec, linear: &Linear, value: &TokenStream) -> TokenStream {
+    let (scale, offset) = (Literal::i128_unsuffixed(linear.scale()), Literal::i128_unsuffixed(linear.offset()));
+    let (min, max) = FieldSpec::raw_range(f.width, linear.signed);
+    let (min, max) = (Literal::i128_unsuffixed(min), Literal::i128_unsuffixed(max));
+    if linear.strict {
+      let fname = f.name();
+      quote! {{
+        let delta: i128 = (#value as i128) - #offset;
+        let raw: i128 = delta.div_euclid(#scale);
+        if delta.rem_euclid(#scale) != 0 || !(#min..=#max).contains(&raw) {
+          return Err(concat!("pack: value not representable in field: ", #fname));
+        }
+        raw
+      }}
+    } else {
+      // Saturate to the raw range rather than wrapping into an unrelated value.
+      quote! { ((#value as i128) - #offset).div_euclid(#scale).clamp(#min, #max) }
+    }
+  }
+
+  // BCD/Gray raw bits → value, as straight-line digit and shift operations.
+  fn decode_raw(&self, f: &FieldSpec, encoding: Encoding, raw: &TokenStream) -> TokenStream {
+    let ty = &f.ty;
+    let (u, _, _) = Self::carriers(f.width);
+    match encoding {
+      Encoding::Bcd => {
+        let fname = f.name();
+        let digits = (0..f.width.div_ceil(4) as u32).map(|i| {
+          let (sh, pow) = (4 * i, Literal::u128_unsuffixed(10u128.pow(i)));
+          let nibble = if i == 0 {
+            quote!(raw & 0xF)
+          } else {
+            quote!((raw >> #sh) & 0xF)
+          };
+          let add = if i == 0 { quote!(digit) } else { quote!(digit * #pow) };
+          quote! {
+            let digit = (#nibble) as u128;
+            if digit > 9 {
+              return Err(concat!("pack: invalid BCD digit in field: ", #fname));
+            }
+            acc += #add;
+          }
+        });
+        quote!({
+          let raw: #u = #raw;
+          let mut acc: u128 = 0;
+          #( #digits )*
+          acc as #ty
+        })
+      }
+      Encoding::Gray => {
+        let steps: Vec<_> = core::iter::successors(Some(1u32), |s| Some(s * 2))
+          .take_while(|&s| s < f.width as u32)
+          .map(|s| quote!(v ^= v >> #s;))
+          .collect();
+        let binding = if steps.is_empty() { quote!(v) } else { quote!(mut v) };
+        quote!({
+          let #binding: #u = #raw;
+          #( #steps )*
+          v as #ty
+        })
+      }
+    }
+  }
+
+  // Value → BCD/Gray raw bits in the field's carrier type.
+  fn encode_value(&self, f: &FieldSpec, encoding: Encoding, value: &TokenStream) -> TokenStream {
+    let (u, _, mask) = Self::carriers(f.width);
+    match encoding {
+      Encoding::Bcd => {
+        let digits = (0..f.width.div_ceil(4) as u32).map(|i| {
+          let (sh, pow) = (4 * i, Literal::u128_unsuffixed(10u128.pow(i)));
+          match i {
+            0 => quote!((v % 10)),
+            _ => quote!(((v / #pow % 10) << #sh)),
+          }
+        });
+        let max = Literal::u128_unsuffixed(Layout::bcd_max(f.width));
+        let fname = f.name();
+        quote!({
+          let v = #value as u128;
+          if v > #max {
+            return Err(concat!("pack: value exceeds BCD range in field: ", #fname));
+          }
+          (#( #digits )|*) as #u
+        })
+      }
+      Encoding::Gray => quote!({
+        let v = (#value as #u) & #mask;
+        v ^ (v >> 1)
+      }),
+    }
+  }
+
+  // Scale factor 2^frac of a fixed-point field, as an `f64` literal.
+  fn fixed_scale(fixed: &Fixed) -> Literal {
+    Literal::f64_suffixed(2f64.powi(fixed.frac as i32))
+  }
+
+  // Raw two's-complement (or unsigned) integer → float, divided by 2^frac.
+  fn fixed_from_raw(&self, f: &FieldSpec, raw: &TokenStream) -> TokenStream {
+    let ty = &f.ty;
+    let Some(fixed) = f.bits.fixed else {
+      unreachable!("fixed-point field without `fixed`")
+    };
+    let scale = Self::fixed_scale(&fixed);
+    if fixed.signed {
+      let ext = self.signed_conversion(raw, f.width as u32, 64);
+      quote!({ (((#ext) as i64) as f64 / #scale) as #ty })
+    } else {
+      quote!({ ((#raw) as f64 / #scale) as #ty })
+    }
+  }
+
+  // Float → raw integer: scale by 2^frac, round, then saturate or reject values outside the
+  // field's range. Evaluates to an `i128` holding the in-range raw value.
+  fn fixed_to_raw(&self, f: &FieldSpec, value: &TokenStream) -> TokenStream {
+    let Some(fixed) = f.bits.fixed else {
+      unreachable!("fixed-point field without `fixed`")
+    };
+    let scale = Self::fixed_scale(&fixed);
+    let (min, max) = FieldSpec::raw_range(f.width, fixed.signed);
+    let (min, max) = (Literal::i128_unsuffixed(min), Literal::i128_unsuffixed(max));
+    let rounded = match fixed.round {
+      Rounding::Trunc => quote! { scaled as i128 },
+      // Compare the (exact) fractional remainder instead of adding 0.5, which can round up in f64.
+      Rounding::Nearest => quote! {{
+        let t = scaled as i128;
+        let rem = scaled - t as f64;
+        if rem >= 0.5 {
+          t.saturating_add(1)
+        } else if rem <= -0.5 {
+          t.saturating_sub(1)
+        } else {
+          t
+        }
+      }},
+      Rounding::Floor => quote! {{ let t = scaled as i128; if (t as f64) > scaled { t.saturating_sub(1) } else { t } }},
+      Rounding::Ceil => quote! {{ let t = scaled as i128; if (t as f64) < scaled { t.saturating_add(1) } else { t } }},
+    };
+    let checked = match fixed.overflow {
+      Overflow::Saturate => quote! { fixed.clamp(#min, #max) },
+      Overflow::Error => {
+        let fname = f.name();
+        quote! {
+          if scaled.is_nan() || !(#min..=#max).contains(&fixed) {
+            return Err(concat!("pack: fixed-point value out of range in field: ", #fname));
+          }
+          fixed
+        }
+      }
+    };
+    quote! {{
+      let scaled: f64 = (#value as f64) * #scale;
+      let fixed: i128 = #rounded;
+      #checked
+    }}
+  }
+
+  // Primitive integer type for a signedness and byte length.
+  fn int_type(signed: bool, bytes: u8) -> TokenStream {
+    let bits = bytes as u16 * 8;
+    let (u, i, _) = Self::carriers(bits);
+    if signed { i } else { u }
+  }
+
+  // Read an integer of type `prim` (matching the field's kind), sign-extending if needed.
+  fn int_from_bytes_expr(&self, f: &FieldSpec, raw: &TokenStream, prim: &TokenStream) -> TokenStream {
     let start = f.start_byte();
-    let k = f.kind;
     if let Some(n) = f.aligned_primitive_len() {
       let elems = (0..n).map(|i| quote! { bytes[#start + #i] });
-      return quote! { <#ty>::from_le_bytes([#(#elems),*]) };
+      return quote! { <#prim>::from_le_bytes([#(#elems),*]) };
     }
-    match k {
-      FieldKind::Bool => quote!(#raw != 0),
-      FieldKind::Int { signed: false, .. } => quote!(#raw),
-      FieldKind::Int { signed: true, bytes } => {
+    match f.kind {
+      FieldKind::Int { signed: true, bytes } | FieldKind::NonZero { signed: true, bytes } => {
         let target_bits: u32 = (bytes as u32) * 8;
-        let signed_result = self.signed_conversion(&raw, f.width as u32, target_bits);
-        quote!({ let ext = #signed_result; ext as #ty })
+        let signed_result = self.signed_conversion(raw, f.width as u32, target_bits);
+        quote!({ let ext = #signed_result; ext as #prim })
       }
-      FieldKind::Custom => self.custom_try_from(ty, &u, &quote!(#ty), &quote!(#raw as #u)),
+      _ => quote!(#raw),
     }
   }
 
   fn gen_to_bytes_stmt(&self, f: &FieldSpec) -> TokenStream {
     let name = &f.ident;
-    let (u, _i, mask) = self.carriers(f.width);
+    let (u, _i, mask) = Self::carriers(f.width);
     let start = f.start_byte();
-    if let Some(n) = f.aligned_primitive_len() {
+    let value = match f.kind {
+      FieldKind::NonZero { .. } => quote! { #name.get() },
+      FieldKind::Float { .. } => quote! { #name.to_bits() },
+      FieldKind::Char => quote! { (#name as u32) },
+      _ if f.bits.range.is_some() => {
+        let check = Self::range_check(f, &quote!(#name));
+        quote! {{ #check #name }}
+      }
+      _ => quote! { #name },
+    };
+    if let Some(n) = f.aligned_primitive_len()
+      && f.bits.none.is_none()
+    {
       let end = start + n;
       return quote! {
-        let le_bytes = #name.to_le_bytes();
+        let le_bytes = #value.to_le_bytes();
         bytes[#start..#end].copy_from_slice(&le_bytes);
       };
     }
@@ -236,25 +1419,84 @@ impl<'a> Generator<'a> {
     });
     let ty = &f.ty;
     let into_val = match f.kind {
-      FieldKind::Custom => self.custom_try_from(ty, &quote!(#ty), &u, &quote!(#name)),
-      _ => quote! { #name as #u },
+      FieldKind::Custom => {
+        let carrier = Self::custom_carrier(f);
+        let conv = match &f.bits.with {
+          Some(path) => self.with_call(f, path, "pack", &quote!(&#name)),
+          None => self.custom_try_from(f, &quote!(#ty), &carrier, &quote!(#name)),
+        };
+        match f.carrier() {
+          Some((signed, bytes)) if signed || bytes as u32 * 8 != Self::carrier_bits(f.width) => {
+            quote!((#conv) as #u)
+          }
+          _ => conv,
+        }
+      }
+      FieldKind::Fixed { .. } => {
+        let fixed = self.fixed_to_raw(f, &value);
+        quote! { #fixed as #u }
+      }
+      _ => match (f.bits.linear, f.bits.encoding) {
+        (Some(linear), _) => {
+          let raw = self.linear_to_raw(f, &linear, &value);
+          quote! { #raw as #u }
+        }
+        (None, Some(encoding)) => self.encode_value(f, encoding, &value),
+        (None, None) => quote! { #value as #u },
+      },
     };
-    quote! {
-      let val: #u = (#into_val) & #mask;
-      #(#parts)*
+    let into_val = if f.bits.invert {
+      quote! { !(#into_val) }
+    } else {
+      into_val
+    };
+    let into_val = if f.bits.reverse {
+      Self::reverse_within(f.width, &quote! { (#into_val) & #mask })
+    } else {
+      into_val
+    };
+    match f.bits.none {
+      Some(none) => {
+        let none = Literal::u128_unsuffixed(none);
+        let fname = f.name();
+        quote! {
+          let val: #u = match #name {
+            Some(#name) => {
+              let val: #u = (#into_val) & #mask;
+              if val == #none {
+                return Err(concat!("pack: value reserved for None in field: ", #fname));
+              }
+              val
+            }
+            None => #none,
+          };
+          #(#parts)*
+        }
+      }
+      None => quote! {
+        let val: #u = (#into_val) & #mask;
+        #(#parts)*
+      },
     }
   }
 
-  pub(super) fn build(mut self) -> TokenStream {
-    self.append_layout_doc();
-    let cleaned = &self.cleaned;
-    let mut out = quote! { #cleaned };
+  // Field constants plus conversion impls between the struct, its byte array and the optional
+  // integer container.
+  pub(super) fn build(&self) -> TokenStream {
+    let (field_consts, layout_consts) = (self.field_consts(), self.layout_consts());
+    let mut out = self.inherent_impl(self.struct_generics, quote! { #field_consts #layout_consts });
+    let (field_accessors, slice_fns) = (self.field_accessors(), self.slice_fns());
+    out.extend(self.inherent_impl(&self.generics, quote! { #field_accessors #slice_fns }));
+    out.extend(self.raw_impls());
+    out.extend(self.view_impls());
+    out.extend(self.volatile_fns());
+    let (pack_fallible, unpack_fallible) = (self.layout.pack_fallible, self.layout.unpack_fallible);
 
     let to_bytes_stmts: Vec<_> = self.layout.fields.iter().map(|f| self.gen_to_bytes_stmt(f)).collect();
     let destructure = self.destructure_bindings();
     let nbytes = self.nbytes;
     let array_ty = quote! { [u8; #nbytes] };
-    let to_bytes_body = if self.layout.fallible {
+    let to_bytes_body = if pack_fallible {
       quote! {
         let mut bytes: #array_ty = [0u8; #nbytes];
         #destructure
@@ -269,21 +1511,21 @@ impl<'a> Generator<'a> {
         bytes
       }
     };
-    let struct_name = self.struct_name;
-    out.extend(self.impl_conv(quote! { #struct_name }, array_ty.clone(), to_bytes_body));
+    let struct_name = &self.self_ty;
+    out.extend(self.impl_conv(pack_fallible, quote! { #struct_name }, array_ty.clone(), to_bytes_body));
 
     let from_bytes_body = {
       let s_expr = self.struct_expr_from_bytes();
-      if self.layout.fallible {
+      if unpack_fallible {
         quote! { let bytes = value; Ok(#s_expr) }
       } else {
         quote! { let bytes = value; #s_expr }
       }
     };
-    out.extend(self.impl_conv(array_ty.clone(), quote! { #struct_name }, from_bytes_body));
+    out.extend(self.impl_conv(unpack_fallible, array_ty.clone(), quote! { #struct_name }, from_bytes_body));
 
     if let Some(int_ty) = &self.int_ty {
-      let to_int_body = if self.layout.fallible {
+      let to_int_body = if pack_fallible {
         quote! {
           let bytes: #array_ty = <#array_ty as core::convert::TryFrom<#struct_name>>::try_from(value)?;
           Ok(<#int_ty>::from_le_bytes(bytes))
@@ -294,9 +1536,9 @@ impl<'a> Generator<'a> {
           <#int_ty>::from_le_bytes(bytes)
         }
       };
-      out.extend(self.impl_conv(quote! { #struct_name }, int_ty.clone(), to_int_body));
+      out.extend(self.impl_conv(pack_fallible, quote! { #struct_name }, int_ty.clone(), to_int_body));
 
-      let from_int_body = if self.layout.fallible {
+      let from_int_body = if unpack_fallible {
         quote! {
           let bytes: #array_ty = value.to_le_bytes();
           <#struct_name as core::convert::TryFrom<#array_ty>>::try_from(bytes)
@@ -307,7 +1549,7 @@ impl<'a> Generator<'a> {
           <#struct_name as core::convert::From<#array_ty>>::from(bytes)
         }
       };
-      out.extend(self.impl_conv(int_ty.clone(), quote! { #struct_name }, from_int_body));
+      out.extend(self.impl_conv(unpack_fallible, int_ty.clone(), quote! { #struct_name }, from_int_body));
     }
 
     out
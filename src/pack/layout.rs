@@ -1,50 +1,113 @@
 use syn::{Fields, Ident, ItemStruct, Type, spanned::Spanned};
 
-use crate::pack::attrs::Attrs;
+use crate::pack::attrs::{Attrs, Bits, Encoding, Linear, Overflow};
 
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub(super) enum FieldKind {
   Bool,
   Int { signed: bool, bytes: u8 },
+  // `NonZeroU8`..`NonZeroI128` (or `NonZero<T>`): an integer that rejects zero on unpack.
+  NonZero { signed: bool, bytes: u8 },
+  // `f32`/`f64`, stored as their IEEE-754 bit pattern.
+  Float { bytes: u8 },
+  // `char`, stored as its Unicode scalar value; unpack rejects invalid scalars.
+  Char,
+  // `f32`/`f64` with `fixed(..)`: stored as a scaled raw integer.
+  Fixed { bytes: u8 },
   Custom,
 }
 
 impl FieldKind {
-  pub(super) fn from_type(ty: &Type) -> Self {
-    let ident = match ty {
-      Type::Path(tp) => tp.path.get_ident().map(|i| i.to_string()),
+  fn int_from_name(name: &str) -> Option<(bool, u8)> {
+    match name {
+      "u8" => Some((false, 1)),
+      "i8" => Some((true, 1)),
+      "u16" => Some((false, 2)),
+      "i16" => Some((true, 2)),
+      "u32" => Some((false, 4)),
+      "i32" => Some((true, 4)),
+      "u64" => Some((false, 8)),
+      "i64" => Some((true, 8)),
+      "u128" => Some((false, 16)),
+      "i128" => Some((true, 16)),
+      _ => None,
+    }
+  }
+
+  // Match `NonZeroU16`, `NonZero<u16>` and their `core::num::`/`std::num::` qualified forms.
+  fn nonzero_from_path(path: &syn::Path) -> Option<(bool, u8)> {
+    let last = std_path_last(path, "num")?;
+    match &last.arguments {
+      syn::PathArguments::None => {
+        let int = last.ident.to_string().strip_prefix("NonZero")?.to_ascii_lowercase();
+        Self::int_from_name(&int)
+      }
+      syn::PathArguments::AngleBracketed(args) if last.ident == "NonZero" && args.args.len() == 1 => {
+        match args.args.first() {
+          Some(syn::GenericArgument::Type(Type::Path(tp))) if tp.qself.is_none() => {
+            Self::int_from_name(&Self::primitive_name(&tp.path)?)
+          }
+          _ => None,
+        }
+      }
       _ => None,
+    }
+  }
+
+  // Name of a primitive type path: `u16`, `core::primitive::u16` or `std::primitive::u16`.
+  fn primitive_name(path: &syn::Path) -> Option<String> {
+    let last = std_path_last(path, "primitive")?;
+    last.arguments.is_none().then(|| last.ident.to_string())
+  }
+
+  pub(super) fn from_type(ty: &Type) -> Self {
+    let path = match ty {
+      Type::Path(tp) if tp.qself.is_none() => &tp.path,
+      _ => return FieldKind::Custom,
     };
-    match ident.as_deref() {
+    if let Some((signed, bytes)) = Self::nonzero_from_path(path) {
+      return FieldKind::NonZero { signed, bytes };
+    }
+    match Self::primitive_name(path).as_deref() {
       Some("bool") => FieldKind::Bool,
-      Some("u8") => FieldKind::Int { signed: false, bytes: 1 },
-      Some("i8") => FieldKind::Int { signed: true, bytes: 1 },
-      Some("u16") => FieldKind::Int { signed: false, bytes: 2 },
-      Some("i16") => FieldKind::Int { signed: true, bytes: 2 },
-      Some("u32") => FieldKind::Int { signed: false, bytes: 4 },
-      Some("i32") => FieldKind::Int { signed: true, bytes: 4 },
-      Some("u64") => FieldKind::Int { signed: false, bytes: 8 },
-      Some("i64") => FieldKind::Int { signed: true, bytes: 8 },
-      Some("u128") => FieldKind::Int { signed: false, bytes: 16 },
-      Some("i128") => FieldKind::Int { signed: true, bytes: 16 },
-      _ => FieldKind::Custom,
+      Some("f32") => FieldKind::Float { bytes: 4 },
+      Some("f64") => FieldKind::Float { bytes: 8 },
+      Some("char") => FieldKind::Char,
+      Some(name) => match Self::int_from_name(name) {
+        Some((signed, bytes)) => FieldKind::Int { signed, bytes },
+        None => FieldKind::Custom,
+      },
+      None => FieldKind::Custom,
     }
   }
 
   pub(super) fn byte_len(self) -> Option<usize> {
     match self {
-      FieldKind::Int { bytes, .. } => Some(bytes as usize),
+      FieldKind::Int { bytes, .. } | FieldKind::NonZero { bytes, .. } | FieldKind::Float { bytes } => {
+        Some(bytes as usize)
+      }
       _ => None,
     }
   }
 
+  // Whether unpacking this kind can fail at runtime.
+  pub(super) fn unpack_fallible(self) -> bool {
+    matches!(self, FieldKind::NonZero { .. } | FieldKind::Char | FieldKind::Custom)
+  }
+
+  // Whether packing this kind can fail at runtime.
+  pub(super) fn pack_fallible(self) -> bool {
+    matches!(self, FieldKind::Custom)
+  }
+
   pub(super) fn full_bits(self) -> Option<u16> {
     self.byte_len().map(|b| (b * 8) as u16)
   }
 
-  pub(super) fn inferred_width(ty: &Type) -> Option<u16> {
-    match Self::from_type(ty) {
+  pub(super) fn inferred_width(self) -> Option<u16> {
+    match self {
       FieldKind::Bool => Some(1),
+      FieldKind::Char => Some(32),
       kind => kind.full_bits(),
     }
   }
@@ -53,13 +116,115 @@ impl FieldKind {
 #[derive(Clone)]
 pub(super) struct FieldSpec {
   pub ident: Ident,
+  // Value type being packed; for `Option<T>` fields with a `none` sentinel this is `T`.
   pub ty: Type,
   pub width: u16,
   pub start_bit: usize,
   pub kind: FieldKind,
+  pub bits: Bits,
+}
+
+// Last segment of `Name` or `core::<module>::Name` / `std::<module>::Name` (optionally with a
+// leading `::`), the forms under which std items are usually spelled.
+fn std_path_last<'a>(path: &'a syn::Path, module: &str) -> Option<&'a syn::PathSegment> {
+  let segs: Vec<&syn::PathSegment> = path.segments.iter().collect();
+  let (last, prefix) = segs.split_last()?;
+  let prefix_ok = match prefix {
+    [] => path.leading_colon.is_none(),
+    [root, m] => (root.ident == "core" || root.ident == "std") && m.ident == module,
+    _ => false,
+  };
+  (prefix_ok && prefix.iter().all(|s| s.arguments.is_none())).then_some(*last)
+}
+
+// Inner type of `Option<T>` (also `core::option::Option<T>` / `std::option::Option<T>`).
+fn option_inner(ty: &Type) -> Option<&Type> {
+  let Type::Path(tp) = ty else { return None };
+  if tp.qself.is_some() {
+    return None;
+  }
+  let last = std_path_last(&tp.path, "option").filter(|s| s.ident == "Option")?;
+  match &last.arguments {
+    syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first() {
+      Some(syn::GenericArgument::Type(inner)) => Some(inner),
+      _ => None,
+    },
+    _ => None,
+  }
 }
 
 impl FieldSpec {
+  // Whether the stored bits are not simply the value's low bits (scaled, re-encoded, inverted
+  // or reversed).
+  pub(super) fn transforms_raw(&self) -> bool {
+    self.bits.linear.is_some() || self.bits.encoding.is_some() || self.bits.invert || self.bits.reverse
+  }
+
+  // Explicit `carrier = uN|iN` of a custom field, as (signed, bytes).
+  pub(super) fn carrier(&self) -> Option<(bool, u8)> {
+    self
+      .bits
+      .carrier
+      .as_ref()
+      .and_then(|id| FieldKind::int_from_name(&id.to_string()))
+  }
+
+  // Kind of value stored, as reported in the generated `LAYOUT`.
+  pub(super) fn kind_name(&self) -> &'static str {
+    match self.kind {
+      FieldKind::Bool => "bool",
+      FieldKind::Int { .. } => "int",
+      FieldKind::NonZero { .. } => "nonzero",
+      FieldKind::Float { .. } => "float",
+      FieldKind::Char => "char",
+      FieldKind::Fixed { .. } => "fixed",
+      FieldKind::Custom => "custom",
+    }
+  }
+
+  // Whether the stored bits are two's complement (sign-extended on read).
+  pub(super) fn is_signed(&self) -> bool {
+    match self.kind {
+      _ if self.bits.linear.is_some() => self.bits.linear.is_some_and(|l| l.signed),
+      FieldKind::Int { signed, .. } | FieldKind::NonZero { signed, .. } => signed,
+      FieldKind::Fixed { .. } => self.bits.fixed.is_some_and(|fx| fx.signed),
+      FieldKind::Custom => self.carrier().is_some_and(|(signed, _)| signed),
+      _ => false,
+    }
+  }
+
+  // Field name for messages and docs; tuple fields use their index.
+  pub(super) fn name(&self) -> String {
+    let name = self.ident.to_string();
+    match name.strip_prefix("__f") {
+      Some(idx) if idx.parse::<usize>().is_ok() => idx.to_string(),
+      _ => name,
+    }
+  }
+
+  pub(super) fn pack_fallible(&self) -> bool {
+    (self.kind.pack_fallible() && !self.bits.infallible)
+      || self.bits.none.is_some()
+      || self.bits.fixed.is_some_and(|fx| fx.overflow == Overflow::Error)
+      || self.bits.linear.is_some_and(|l| l.strict)
+      || self.bits.encoding == Some(Encoding::Bcd)
+      || self.bits.range.is_some()
+  }
+
+  // Range of raw values a W-bit field can hold, as two's complement or unsigned.
+  pub(super) fn raw_range(width: u16, signed: bool) -> (i128, i128) {
+    let w = width as u32;
+    if signed {
+      (-(1i128 << (w - 1)), (1i128 << (w - 1)) - 1)
+    } else {
+      (0, (1i128 << w) - 1)
+    }
+  }
+  pub(super) fn unpack_fallible(&self) -> bool {
+    (self.kind.unpack_fallible() && !self.bits.infallible)
+      || self.bits.encoding == Some(Encoding::Bcd)
+      || self.bits.range.is_some()
+  }
   pub(super) fn is_byte_aligned(&self) -> bool {
     self.start_bit % 8 == 0 && self.width % 8 == 0
   }
@@ -67,7 +232,7 @@ impl FieldSpec {
     self.start_bit / 8
   }
   pub(super) fn aligned_primitive_len(&self) -> Option<usize> {
-    if self.is_byte_aligned() && self.kind.full_bits() == Some(self.width) {
+    if self.is_byte_aligned() && self.kind.full_bits() == Some(self.width) && !self.transforms_raw() {
       self.kind.byte_len()
     } else {
       None
@@ -75,10 +240,28 @@ impl FieldSpec {
   }
 }
 
+// A field excluded from packing with `#[pack(ignore)]` / `#[pack(default = expr)]`.
+pub(super) struct IgnoredField {
+  pub ident: Ident,
+  // Position among all of the struct's fields.
+  pub index: usize,
+  pub ty: Type,
+  pub default: Option<syn::Expr>,
+}
+
+// A struct field in declaration order: either packed or ignored.
+pub(super) enum Member<'a> {
+  Packed(&'a FieldSpec),
+  Ignored(&'a IgnoredField),
+}
+
 pub(super) struct Layout {
   pub fields: Vec<FieldSpec>,
+  pub ignored: Vec<IgnoredField>,
   pub is_tuple: bool,
-  pub fallible: bool,
+  // Whether struct → bytes and bytes → struct can fail, respectively.
+  pub pack_fallible: bool,
+  pub unpack_fallible: bool,
 }
 
 impl Layout {
@@ -92,13 +275,118 @@ impl Layout {
       Fields::Unit => (Vec::new(), false),
     };
     let mut fields: Vec<FieldSpec> = Vec::with_capacity(iter.len());
+    let mut ignored = Vec::new();
     for (field, idx) in iter {
-      if let Some(spec) = Self::process_field(total_bits, &mut cursor_bit, field, idx, item, errors) {
-        fields.push(spec);
+      match Attrs::parse_ignore(&field.attrs) {
+        Some(Ok(ignore)) => {
+          if field
+            .attrs
+            .iter()
+            .any(|a| a.path().is_ident("bits") || a.path().is_ident("skip"))
+          {
+            errors.push(syn::Error::new(field.span(), "ignored fields cannot have `#[bits]` or `#[skip]`"));
+            continue;
+          }
+          let ident = field
+            .ident
+            .clone()
+            .unwrap_or_else(|| Ident::new(&format!("__f{idx}"), field.span()));
+          ignored.push(IgnoredField { ident, index: idx, ty: field.ty.clone(), default: ignore.default });
+        }
+        Some(Err(e)) => errors.push(e),
+        None => {
+          if let Some(spec) = Self::process_field(total_bits, &mut cursor_bit, field, idx, item, errors) {
+            fields.push(spec);
+          }
+        }
       }
     }
-    let fallible = fields.iter().any(|f| matches!(f.kind, FieldKind::Custom));
-    Layout { fields, is_tuple, fallible }
+    let pack_fallible = fields.iter().any(|f| f.pack_fallible());
+    let unpack_fallible = fields.iter().any(|f| f.unpack_fallible());
+    Layout { fields, ignored, is_tuple, pack_fallible, unpack_fallible }
+  }
+
+  // All fields in declaration order.
+  pub(super) fn members(&self) -> Vec<Member<'_>> {
+    let mut packed = self.fields.iter();
+    (0..self.fields.len() + self.ignored.len())
+      .filter_map(|i| match self.ignored.iter().find(|ig| ig.index == i) {
+        Some(ig) => Some(Member::Ignored(ig)),
+        None => packed.next().map(Member::Packed),
+      })
+      .collect()
+  }
+
+  // Validate `scale`/`offset`: integer fields only, and every raw value must map into the
+  // declared type's range.
+  fn check_linear(width: u16, kind: FieldKind, ty: &Type, linear: Linear) -> Result<(), String> {
+    let FieldKind::Int { signed, bytes } = kind else {
+      return Err("`scale`/`offset` require an integer field".into());
+    };
+    if linear.scale.is_none() && linear.offset.is_none() {
+      return Err("`strict`/`signed` require `scale` or `offset`".into());
+    }
+    if bytes > 8 || width > 64 {
+      return Err("`scale`/`offset` support fields and types up to 64 bits".into());
+    }
+    let (raw_lo, raw_hi) = FieldSpec::raw_range(width, linear.signed);
+    let map = |raw: i128| {
+      raw
+        .checked_mul(linear.scale())
+        .and_then(|v| v.checked_add(linear.offset()))
+    };
+    let (ty_lo, ty_hi) = FieldSpec::raw_range(bytes as u16 * 8, signed);
+    match (map(raw_lo), map(raw_hi)) {
+      (Some(lo), Some(hi)) if lo >= ty_lo && hi <= ty_hi => Ok(()),
+      (Some(lo), Some(hi)) => Err(format!("transformed range {lo}..={hi} does not fit in `{}`", quote::quote!(#ty))),
+      _ => Err("transformed range overflows".into()),
+    }
+  }
+
+  // Validate `bcd`/`gray`: unsigned integer fields only, not combined with `scale`/`offset`,
+  // and every BCD value the field can hold must fit the declared type.
+  fn check_encoding(width: u16, kind: FieldKind, ty: &Type, bits: &Bits) -> Result<(), String> {
+    let FieldKind::Int { signed: false, bytes } = kind else {
+      return Err("`bcd`/`gray` require an unsigned integer field".into());
+    };
+    if bits.linear.is_some() {
+      return Err("`bcd`/`gray` cannot be combined with `scale`/`offset`".into());
+    }
+    if bits.encoding == Some(Encoding::Bcd) {
+      let max = Self::bcd_max(width);
+      if max > u128::MAX >> (128 - bytes as u32 * 8) {
+        return Err(format!("BCD range 0..={max} does not fit in `{}`", quote::quote!(#ty)));
+      }
+    }
+    Ok(())
+  }
+
+  // Values a W-bit integer field can represent after its `scale`/`offset` or `bcd` transform, or
+  // `None` when the field spans the full 128 bits.
+  fn stored_value_range(width: u16, kind: FieldKind, bits: &Bits) -> Option<(i128, i128)> {
+    let FieldKind::Int { signed, .. } = kind else {
+      return None;
+    };
+    if let Some(linear) = bits.linear {
+      let (lo, hi) = FieldSpec::raw_range(width, linear.signed);
+      let map = |raw: i128| raw.checked_mul(linear.scale())?.checked_add(linear.offset());
+      return map(lo).zip(map(hi));
+    }
+    match bits.encoding {
+      Some(Encoding::Bcd) => Some((0, Self::bcd_max(width) as i128)),
+      _ if width >= 128 => None,
+      Some(Encoding::Gray) => Some(FieldSpec::raw_range(width, false)),
+      None => Some(FieldSpec::raw_range(width, signed)),
+    }
+  }
+
+  // Largest decimal value a W-bit BCD field can hold (the top nibble may be partial).
+  pub(super) fn bcd_max(width: u16) -> u128 {
+    (0..width.div_ceil(4)).fold(0u128, |acc, i| {
+      let bits = (width - i * 4).min(4);
+      let digit = ((1u128 << bits) - 1).min(9);
+      acc + digit * 10u128.pow(i as u32)
+    })
   }
 
   fn process_field(
@@ -115,22 +403,60 @@ impl Layout {
       _ => syn::Ident::new("_", field.span()),
     };
 
-    // width
-    let width = match Attrs::parse_bits(&field.attrs) {
-      Some(Ok(bits)) => bits.width,
+    let bits = match Attrs::parse_bits(&field.attrs) {
+      Some(Ok(bits)) => bits,
       Some(Err(e)) => {
         errors.push(e);
         return None;
       }
-      None => match FieldKind::inferred_width(&field.ty) {
-        Some(w) => w,
+      None => Bits::default(),
+    };
+
+    // `none = V` packs `Option<T>` as `T`, with V standing in for `None`.
+    let ty = match (bits.none, option_inner(&field.ty)) {
+      (Some(_), Some(inner)) => inner.clone(),
+      (Some(_), None) => {
+        errors.push(syn::Error::new(field.ty.span(), "`none = V` requires an `Option<T>` field"));
+        return None;
+      }
+      (None, _) => field.ty.clone(),
+    };
+
+    // `int` declares an alias of a primitive integer; bare `int` is unsigned and sized by the width.
+    let mut kind = match &bits.int {
+      None => FieldKind::from_type(&ty),
+      Some(Some(name)) => match FieldKind::int_from_name(&name.to_string()) {
+        Some((signed, bytes)) => FieldKind::Int { signed, bytes },
+        None => {
+          errors.push(syn::Error::new(name.span(), "`int` must be one of u8..u128 or i8..i128"));
+          return None;
+        }
+      },
+      Some(None) => match bits.width {
+        Some(w) => FieldKind::Int { signed: false, bytes: (w as u32).div_ceil(8).next_power_of_two() as u8 },
         None => {
-          errors.push(syn::Error::new(field.span(), "missing #[bits(W)] for this field type"));
+          errors.push(syn::Error::new(field.span(), "`int` requires a width or `int = uN|iN`"));
           return None;
         }
       },
     };
 
+    // width
+    let width = match bits.width.or_else(|| kind.inferred_width()) {
+      Some(w) => w,
+      None => {
+        errors.push(syn::Error::new(field.span(), "missing #[bits(W)] for this field type"));
+        return None;
+      }
+    };
+    if let Some(none) = bits.none
+      && width < 128
+      && none >> width != 0
+    {
+      errors.push(syn::Error::new(field.span(), format!("`none` value does not fit in {width} bits")));
+      return None;
+    }
+
     // optional skip
     if let Some(skip) = Attrs::parse_skip(&field.attrs) {
       match skip {
@@ -149,6 +475,95 @@ impl Layout {
       }
     }
 
+    if bits.fixed.is_some() {
+      match kind {
+        FieldKind::Float { bytes } => kind = FieldKind::Fixed { bytes },
+        _ => {
+          errors.push(syn::Error::new(field.ty.span(), "`fixed` requires an `f32` or `f64` field"));
+          return None;
+        }
+      }
+    }
+    if bits.with.is_some() {
+      if bits.fixed.is_some() || bits.linear.is_some() || bits.encoding.is_some() || bits.int.is_some() {
+        let msg = "`with` cannot be combined with `fixed`, `int`, `scale`/`offset`, `bcd` or `gray`";
+        errors.push(syn::Error::new(field.span(), msg));
+        return None;
+      }
+      kind = FieldKind::Custom;
+    }
+    if bits.infallible && (kind != FieldKind::Custom || bits.with.is_some()) {
+      let msg = "`infallible` requires a custom field type converted with `From` (not `with`)";
+      errors.push(syn::Error::new(field.span(), msg));
+      return None;
+    }
+    if let Some(carrier) = &bits.carrier {
+      let msg = match FieldKind::int_from_name(&carrier.to_string()) {
+        _ if kind != FieldKind::Custom => Some("`carrier` requires a custom field type".to_string()),
+        None => Some("`carrier` must be one of u8..u128 or i8..i128".to_string()),
+        Some((_, bytes)) if (bytes as u16) * 8 < width => Some(format!("`carrier` must be at least {width} bits wide")),
+        Some(_) => None,
+      };
+      if let Some(msg) = msg {
+        errors.push(syn::Error::new(carrier.span(), msg));
+        return None;
+      }
+    }
+    if let Some((lo, hi)) = bits.range {
+      let fits = match kind {
+        FieldKind::Int { signed: true, bytes: 16 } => true,
+        FieldKind::Int { signed: false, bytes: 16 } => lo >= 0,
+        FieldKind::Int { signed, bytes } => {
+          let (min, max) = FieldSpec::raw_range(bytes as u16 * 8, signed);
+          lo >= min && hi <= max
+        }
+        _ => {
+          errors.push(syn::Error::new(field.span(), "`range` requires an integer field"));
+          return None;
+        }
+      };
+      if !fits {
+        let msg = format!("`range` {lo}..={hi} does not fit in `{}`", quote::quote!(#ty));
+        errors.push(syn::Error::new(field.span(), msg));
+        return None;
+      }
+      if let Some((min, max)) = Self::stored_value_range(width, kind, &bits)
+        && (lo < min || hi > max)
+      {
+        let msg = format!("`range` {lo}..={hi} does not fit in {width} bits ({min}..={max})");
+        errors.push(syn::Error::new(field.span(), msg));
+        return None;
+      }
+    }
+    match kind {
+      FieldKind::Float { bytes } if width as usize != bytes as usize * 8 => {
+        let msg = format!("floating-point fields must be exactly {} bits wide", bytes as usize * 8);
+        errors.push(syn::Error::new(field.span(), msg));
+        return None;
+      }
+      FieldKind::Fixed { .. } if width > 64 => {
+        errors.push(syn::Error::new(field.span(), "fixed-point fields must be 1..=64 bits wide"));
+        return None;
+      }
+      _ if bits.linear.is_some() => {
+        if let Err(msg) = Self::check_linear(width, kind, &ty, bits.linear.unwrap_or_default()) {
+          errors.push(syn::Error::new(field.span(), msg));
+          return None;
+        }
+      }
+      _ if bits.encoding.is_some() => {
+        if let Err(msg) = Self::check_encoding(width, kind, &ty, &bits) {
+          errors.push(syn::Error::new(field.span(), msg));
+          return None;
+        }
+      }
+      FieldKind::Char if !(21..=32).contains(&width) => {
+        errors.push(syn::Error::new(field.span(), "`char` fields must be 21..=32 bits wide"));
+        return None;
+      }
+      _ => {}
+    }
+
     // allocate sequentially
     if cursor_bit.saturating_add(width as usize) > total_bits {
       errors.push(syn::Error::new(field.span(), "not enough space for field"));
@@ -157,7 +572,6 @@ impl Layout {
     let start_bit = *cursor_bit;
     *cursor_bit += width as usize;
 
-    let kind = FieldKind::from_type(&field.ty);
-    Some(FieldSpec { ident, ty: field.ty.clone(), width, start_bit, kind })
+    Some(FieldSpec { ident, ty, width, start_bit, kind, bits })
   }
 }
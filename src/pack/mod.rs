@@ -1,9 +1,10 @@
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{ToTokens, quote};
-use syn::ItemStruct;
+use syn::{ItemStruct, spanned::Spanned};
 
 mod args;
 mod attrs;
+mod cfg;
 mod diagram;
 mod generator;
 mod layout;
@@ -28,12 +29,38 @@ pub(super) fn expand(args: TokenStream2, item: ItemStruct) -> TokenStream2 {
   let opts = ContainerOpts::parse(args, &mut errors);
   let name = item.ident.clone();
 
-  let layout = Layout::new(&item, opts.bytes_len, &mut errors);
-  if layout.fields.is_empty() && !errors.is_empty() {
-    return emit_or_compile_errors(quote! {}, errors);
+  let mut cleaned = Attrs::strip_field_attrs(item.clone());
+  let mut docs = Vec::new();
+  let mut impls = TokenStream2::new();
+  for variant in cfg::variants(&item, &mut errors) {
+    let mut layout_errors = Vec::new();
+    let layout = Layout::new(&variant.item, opts.bytes_len, &mut layout_errors);
+    if opts.views {
+      layout_errors.extend(Generator::view_name_errors(&layout));
+    }
+    match &variant.cond {
+      _ if layout_errors.is_empty() => {}
+      // Only report problems of configurations that are actually compiled.
+      Some(cond) => {
+        impls.extend(cfg::gated_errors(cond, layout_errors));
+        continue;
+      }
+      None => {
+        errors.extend(layout_errors);
+        continue;
+      }
+    }
+    let generator = Generator::new(&name, &opts, &layout, &cleaned.generics, variant.cond.as_ref());
+    docs.extend(generator.layout_doc());
+    impls.extend(generator.build());
+    impls.extend(generator.columns(&cleaned.vis));
   }
-
-  let cleaned = Attrs::strip_field_attrs(item);
-  let tokens = Generator::new(&name, opts.bytes_len, opts.int_ident.as_ref(), opts.bit_order, &layout, cleaned).build();
-  emit_or_compile_errors(tokens, errors)
+  if (opts.raw.is_some() || opts.views || opts.columns) && !cleaned.generics.params.is_empty() {
+    let msg = "`raw = Name`, `views` and `columns` are not supported on generic structs";
+    errors.push(syn::Error::new(cleaned.generics.span(), msg));
+  }
+  impls.extend(Generator::raw_struct(&name, &cleaned.vis, &opts));
+  impls.extend(Generator::view_structs(&name, &cleaned.vis, &opts));
+  cleaned.attrs.extend(docs);
+  emit_or_compile_errors(quote! { #cleaned #impls }, errors)
 }
@@ -0,0 +1,60 @@
+#![allow(dead_code)]
+
+use core::num::NonZeroU8;
+
+#[packbits::pack(bytes = 5, accessors)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Header {
+  #[bits(4)]
+  ver: u8,
+  #[bits(12)]
+  len: u16,
+  #[bits(7, range = 0..=99)]
+  pct: u8,
+  #[bits(5, none = 0x1F)]
+  slot: Option<u8>,
+  div: NonZeroU8,
+}
+
+#[packbits::pack(bytes = 1, accessors)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Pair(#[bits(4)] u8, #[bits(4)] i8);
+
+#[test]
+fn read_decodes_a_single_field() {
+  let h = Header { ver: 0x3, len: 0xABC, pct: 42, slot: None, div: NonZeroU8::new(9).unwrap() };
+  let bytes: [u8; 5] = h.try_into().unwrap();
+  assert_eq!(Header::read_ver(&bytes), 0x3);
+  assert_eq!(Header::read_len(&bytes), 0xABC);
+  assert_eq!(Header::read_pct(&bytes), Ok(42));
+  assert_eq!(Header::read_slot(&bytes), None);
+  assert_eq!(Header::read_div(&bytes), Ok(NonZeroU8::new(9).unwrap()));
+}
+
+#[test]
+fn write_updates_only_that_field() {
+  let h = Header { ver: 0x3, len: 0xABC, pct: 42, slot: Some(4), div: NonZeroU8::new(9).unwrap() };
+  let mut bytes: [u8; 5] = h.try_into().unwrap();
+  Header::write_len(&mut bytes, 0x123);
+  Header::write_slot(&mut bytes, None).unwrap();
+  Header::write_div(&mut bytes, NonZeroU8::new(200).unwrap());
+  let back = Header::try_from(bytes).unwrap();
+  assert_eq!(back, Header { len: 0x123, slot: None, div: NonZeroU8::new(200).unwrap(), ..h });
+}
+
+#[test]
+fn fallible_directions_return_results() {
+  let mut bytes = [0u8; 5];
+  assert!(Header::write_pct(&mut bytes, 100).is_err());
+  assert!(Header::write_slot(&mut bytes, Some(0x1F)).is_err());
+  assert_eq!(bytes, [0; 5]);
+  assert!(Header::read_div(&bytes).is_err());
+}
+
+#[test]
+fn tuple_accessors_use_indices() {
+  let mut bytes: [u8; 1] = Pair(0x5, -2).into();
+  assert_eq!((Pair::read_0(&bytes), Pair::read_1(&bytes)), (0x5, -2));
+  Pair::write_1(&mut bytes, 7);
+  assert_eq!(Pair::from(bytes), Pair(0x5, 7));
+}
@@ -0,0 +1,78 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+// RTC time registers: seconds/minutes in 7-bit BCD, hours in 6-bit BCD.
+#[packbits::pack(bytes = 3)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Time {
+  #[bits(7, bcd)]
+  seconds: u8,
+  #[skip(1)]
+  #[bits(7, bcd)]
+  minutes: u8,
+  #[skip(1)]
+  #[bits(6, bcd)]
+  hours: u8,
+}
+
+#[test]
+fn bcd_roundtrip() {
+  let t = Time { seconds: 59, minutes: 7, hours: 23 };
+  let bytes: [u8; 3] = t.try_into().unwrap();
+  assert_eq!(bytes, [0x59, 0x07, 0x23]);
+  assert_eq!(Time::try_from(bytes), Ok(t));
+}
+
+#[test]
+fn invalid_bcd_nibble_is_rejected() {
+  let err = Time::try_from([0x5A, 0x00, 0x00]).unwrap_err();
+  assert_eq!(err, "pack: invalid BCD digit in field: seconds");
+  assert!(Time::try_from([0x00, 0x00, 0x0F]).is_err());
+}
+
+#[test]
+fn bcd_values_that_do_not_fit_are_rejected() {
+  let err = <[u8; 3]>::try_from(Time { seconds: 80, minutes: 0, hours: 0 }).unwrap_err();
+  assert_eq!(err, "pack: value exceeds BCD range in field: seconds");
+  assert!(<[u8; 3]>::try_from(Time { seconds: 0, minutes: 0, hours: 40 }).is_err());
+  assert_eq!(<[u8; 3]>::try_from(Time { seconds: 79, minutes: 0, hours: 39 }), Ok([0x79, 0x00, 0x39]));
+}
+
+#[packbits::pack(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Encoder {
+  #[bits(10, gray)]
+  position: u16,
+  #[bits(12, bcd)]
+  count: u16,
+}
+
+#[test]
+fn gray_roundtrip_all_positions() {
+  for position in 0..1024u16 {
+    let e = Encoder { position, count: 999 };
+    let word: u32 = e.try_into().unwrap();
+    assert_eq!(word >> 10, 0x999);
+    let gray = (word & 0x3FF) as u16;
+    assert_eq!(gray, position ^ (position >> 1));
+    assert_eq!(Encoder::try_from(word), Ok(e));
+  }
+}
+
+#[packbits::pack(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Counter {
+  #[bits(16, bcd)]
+  count: u16,
+  #[bits(1, gray)]
+  bit: u8,
+}
+
+#[test]
+fn wide_bcd_roundtrip() {
+  let c = Counter { count: 9876, bit: 1 };
+  let raw: u32 = c.try_into().unwrap();
+  assert_eq!(raw, 0x1_9876);
+  assert_eq!(Counter::try_from(raw), Ok(c));
+}
@@ -0,0 +1,81 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+// A domain type that only converts through `u32`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Address(u32);
+
+impl From<u32> for Address {
+  fn from(v: u32) -> Self {
+    Address(v)
+  }
+}
+
+impl From<Address> for u32 {
+  fn from(a: Address) -> u32 {
+    a.0
+  }
+}
+
+// A signed domain type that converts through `i16`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Offset(i16);
+
+impl From<i16> for Offset {
+  fn from(v: i16) -> Self {
+    Offset(v)
+  }
+}
+
+impl From<Offset> for i16 {
+  fn from(o: Offset) -> i16 {
+    o.0
+  }
+}
+
+#[packbits::pack(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Access {
+  #[bits(9, carrier = u32)]
+  addr: Address,
+  #[bits(12, carrier = i16)]
+  offset: Offset,
+  #[bits(8, carrier = i8)]
+  bias: Bias,
+  #[bits(3)]
+  flags: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Bias(i8);
+
+impl From<i8> for Bias {
+  fn from(v: i8) -> Self {
+    Bias(v)
+  }
+}
+
+impl From<Bias> for i8 {
+  fn from(b: Bias) -> i8 {
+    b.0
+  }
+}
+
+#[test]
+fn explicit_carriers_roundtrip() {
+  let a = Access { addr: Address(0x1AB), offset: Offset(-300), bias: Bias(-2), flags: 5 };
+  let raw: u32 = a.try_into().unwrap();
+  assert_eq!(raw & 0x1FF, 0x1AB);
+  assert_eq!((raw >> 9) & 0xFFF, (-300i16 as u16 as u32) & 0xFFF);
+  assert_eq!((raw >> 21) & 0xFF, 0xFE);
+  assert_eq!(Access::try_from(raw), Ok(a));
+}
+
+#[test]
+fn signed_carrier_sign_extends() {
+  let raw: u32 = 0xFFF << 9;
+  let a = Access::try_from(raw).unwrap();
+  assert_eq!(a.offset, Offset(-1));
+  assert_eq!(a.addr, Address(0));
+}
@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[packbits::pack(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Reg {
+  #[bits(4)]
+  ver: u8,
+  // Never compiled: takes no bits.
+  #[cfg(any())]
+  #[bits(4)]
+  gone: u8,
+  #[cfg(test)]
+  #[bits(4)]
+  extra: u8,
+  #[cfg(not(test))]
+  #[bits(2)]
+  legacy: u8,
+  #[bits(8)]
+  len: u8,
+}
+
+#[packbits::pack(bytes = 1)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Pair(#[bits(4)] u8, #[cfg(any())] bool, #[bits(4)] u8);
+
+#[test]
+fn positions_follow_the_active_configuration() {
+  let r = Reg { ver: 0x1, extra: 0x2, len: 0xAB };
+  let raw: u16 = r.into();
+  assert_eq!(raw, 0xAB21);
+  assert_eq!(Reg::from(raw), r);
+}
+
+#[test]
+fn cfg_in_tuple_structs_shifts_indices() {
+  let bytes: [u8; 1] = Pair(0x3, 0xC).into();
+  assert_eq!(bytes, [0xC3]);
+  assert_eq!(Pair::from(bytes), Pair(0x3, 0xC));
+}
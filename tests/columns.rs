@@ -0,0 +1,125 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[packbits::pack(u32, columns)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Header {
+  #[bits(4)]
+  ver: u8,
+  #[bits(12)]
+  len: u16,
+  #[bits(8, none = 0xFF)]
+  hop: Option<u8>,
+  #[bits(7)]
+  ttl: u8,
+  urgent: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+  Idle,
+  Run,
+}
+
+impl TryFrom<u8> for Mode {
+  type Error = &'static str;
+  fn try_from(v: u8) -> Result<Self, Self::Error> {
+    match v {
+      0 => Ok(Mode::Idle),
+      1 => Ok(Mode::Run),
+      _ => Err("bad mode"),
+    }
+  }
+}
+
+impl From<Mode> for u8 {
+  fn from(m: Mode) -> u8 {
+    m as u8
+  }
+}
+
+#[packbits::pack(bytes = 2, columns)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Sample(
+  #[bits(2)] Mode,
+  #[skip(2)]
+  #[bits(12)]
+  u16,
+);
+
+#[test]
+fn records_decode_into_columns() {
+  let records = [
+    Header { ver: 1, len: 0x123, hop: Some(3), ttl: 64, urgent: false },
+    Header { ver: 2, len: 0x456, hop: None, ttl: 1, urgent: true },
+    Header { ver: 15, len: 0xFFF, hop: Some(0), ttl: 127, urgent: false },
+  ];
+  let mut bytes = [0u8; 12];
+  Header::pack_slice(&records, &mut bytes).unwrap();
+
+  let columns = HeaderColumns::decode(&bytes).unwrap();
+  assert_eq!(columns.len(), 3);
+  assert_eq!(columns.ver, [1, 2, 15]);
+  assert_eq!(columns.len, [0x123, 0x456, 0xFFF]);
+  assert_eq!(columns.hop, [Some(3), None, Some(0)]);
+  assert_eq!(columns.ttl, [64, 1, 127]);
+  assert_eq!(columns.urgent, [false, true, false]);
+}
+
+#[test]
+fn columns_extend_and_reject_partial_records() {
+  let mut columns = HeaderColumns::with_capacity(4);
+  assert!(columns.is_empty());
+  let one: [u8; 4] = Header { ver: 7, len: 9, hop: None, ttl: 2, urgent: true }
+    .try_into()
+    .unwrap();
+  columns.extend_from_bytes(&one).unwrap();
+  columns.extend_from_bytes(&one).unwrap();
+  assert_eq!(columns.len(), 2);
+  assert_eq!(columns.ver, [7, 7]);
+
+  assert!(columns.extend_from_bytes(&one[..3]).is_err());
+  assert_eq!(columns.len(), 2);
+}
+
+#[test]
+fn tuple_columns_stop_at_a_bad_record() {
+  let good: [u8; 2] = Sample(Mode::Run, 0x0AB).try_into().unwrap();
+  let bytes = [good[0], good[1], 0x03, 0x00, good[0], good[1]];
+
+  let mut columns = SampleColumns::default();
+  assert!(columns.extend_from_bytes(&bytes).is_err());
+  assert_eq!(columns.f0, [Mode::Run]);
+  assert_eq!(columns.f1, [0x0AB]);
+  assert_eq!(SampleColumns::decode(&good).unwrap().f0, [Mode::Run]);
+}
+
+#[packbits::pack(u8, columns)]
+struct Flags {
+  #[bits(3)]
+  level: u8,
+  #[cfg(test)]
+  #[bits(2)]
+  probe: u8,
+  #[cfg(not(test))]
+  #[bits(4)]
+  spare: u8,
+}
+
+#[test]
+fn columns_follow_the_active_configuration() {
+  let columns = FlagsColumns::decode(&[0b01_101, 0b10_010]).unwrap();
+  assert_eq!(columns.level, [0b101, 0b010]);
+  assert_eq!(columns.probe, [0b01, 0b10]);
+}
+
+#[packbits::pack(bytes = 0, columns)]
+struct Marker {}
+
+#[test]
+fn zero_sized_records_decode_only_from_empty_input() {
+  let columns = MarkerColumns::decode(&[]).unwrap();
+  assert!(columns.is_empty());
+  assert!(MarkerColumns::decode(&[1]).is_err());
+}
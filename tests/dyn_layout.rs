@@ -0,0 +1,67 @@
+#![allow(dead_code)]
+
+packbits::dyn_layout!(pub(crate));
+
+#[packbits::pack(bytes = 4, msb)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Frame {
+  flag: bool,
+  #[bits(5)]
+  delta: i8,
+  #[skip(2)]
+  #[bits(11)]
+  len: u16,
+  #[bits(13)]
+  id: i16,
+}
+
+#[packbits::pack(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Lsb {
+  #[bits(3)]
+  a: u8,
+  #[bits(9)]
+  b: i16,
+  #[bits(4)]
+  c: u8,
+}
+
+const FRAME: DynLayout = DynLayout::new(Frame::LAYOUT, Frame::LAYOUT_BYTES, Frame::LAYOUT_MSB);
+
+#[test]
+fn decode_matches_generated_conversions() {
+  let f = Frame { flag: true, delta: -7, len: 0x5A5, id: -1234 };
+  let bytes: [u8; 4] = f.into();
+  let values: Vec<_> = FRAME.decode(&bytes).unwrap().collect();
+  assert_eq!(values, [("flag", 1), ("delta", -7), ("len", 0x5A5), ("id", -1234)]);
+  assert_eq!(FRAME.get(&bytes, "id"), Ok(-1234));
+  assert_eq!(FRAME.byte_len(), 4);
+}
+
+#[test]
+fn encode_matches_generated_conversions() {
+  let l = Lsb { a: 5, b: -200, c: 0xC };
+  let expected: [u8; 2] = l.into();
+  let dl = DynLayout::new(Lsb::LAYOUT, Lsb::LAYOUT_BYTES, Lsb::LAYOUT_MSB);
+  let mut out = [0u8; 2];
+  dl.encode(&[("a", 5), ("b", -200), ("c", 0xC)], &mut out).unwrap();
+  assert_eq!(out, expected);
+
+  let f = Frame { flag: false, delta: 3, len: 0x7FF, id: 77 };
+  let mut out = [0xFFu8; 4];
+  FRAME
+    .encode(&[("flag", 0), ("delta", 3), ("len", 0x7FF), ("id", 77)], &mut out)
+    .unwrap();
+  // the skipped bits keep their previous value
+  let expected: [u8; 4] = f.into();
+  assert_eq!(out[0] & 0b1111_1100, expected[0] & 0b1111_1100);
+  assert_eq!(out[1..], expected[1..]);
+  assert_eq!(Frame::from(out), f);
+}
+
+#[test]
+fn errors_are_reported() {
+  assert!(FRAME.decode(&[0u8; 3]).is_err());
+  assert_eq!(FRAME.get(&[0u8; 4], "nope"), Err("pack: unknown field name"));
+  assert_eq!(FRAME.encode(&[("nope", 1)], &mut [0u8; 4]), Err("pack: unknown field name"));
+}
@@ -0,0 +1,89 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[packbits::pack(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Header {
+  #[bits(4)]
+  ver: u8,
+  #[skip(2)]
+  #[bits(10)]
+  len: u16,
+  flag: bool,
+}
+
+#[packbits::pack(bytes = 2, msb)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Msb {
+  #[bits(3)]
+  a: u8,
+  #[bits(7)]
+  b: u8,
+}
+
+#[packbits::pack(u32, msb)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct MsbAligned {
+  #[bits(8)]
+  head: u8,
+  #[bits(16)]
+  word: u16,
+  #[bits(4)]
+  low: u8,
+  #[bits(4, invert)]
+  flags: u8,
+}
+
+// `Msb::b` (bits 3..10) is split across bytes out of value order and `MsbAligned::flags` is
+// inverted, so neither gets SHIFT/MASK; these fallbacks only resolve when the inherent ones are absent.
+trait NoShift {
+  const B_SHIFT: u32 = u32::MAX;
+  const FLAGS_SHIFT: u32 = u32::MAX;
+}
+impl NoShift for Msb {}
+impl NoShift for MsbAligned {}
+
+#[packbits::pack(bytes = 3)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Tuple(#[bits(12)] u16, #[bits(12)] u16);
+
+#[test]
+fn constants_match_the_packed_layout() {
+  assert_eq!((Header::VER_BITS, Header::VER_SHIFT, Header::VER_MASK), (4, 0, 0xF));
+  assert_eq!((Header::LEN_BITS, Header::LEN_SHIFT, Header::LEN_MASK), (10, 6, 0x3FF << 6));
+  assert_eq!((Header::FLAG_BITS, Header::FLAG_SHIFT, Header::FLAG_MASK), (1, 16, 1 << 16));
+
+  let h = Header { ver: 0x5, len: 0x2AB, flag: true };
+  let raw: u32 = h.into();
+  assert_eq!((raw & Header::LEN_MASK) >> Header::LEN_SHIFT, 0x2AB);
+  assert_eq!((raw & Header::VER_MASK) >> Header::VER_SHIFT, 0x5);
+  assert_eq!(raw & Header::FLAG_MASK, Header::FLAG_MASK);
+}
+
+#[test]
+fn msb_masks_follow_in_byte_numbering() {
+  let _: u16 = Msb::A_MASK;
+  assert_eq!((Msb::A_SHIFT, Msb::A_MASK), (5, 0b1110_0000));
+  assert_eq!((Msb::B_BITS, Msb::B_SHIFT), (7, u32::MAX));
+}
+
+#[test]
+fn msb_fields_crossing_bytes_extract_with_shift_and_mask() {
+  assert_eq!((MsbAligned::WORD_SHIFT, MsbAligned::WORD_MASK), (8, 0x00FF_FF00));
+  assert_eq!((MsbAligned::LOW_SHIFT, MsbAligned::LOW_MASK), (28, 0xF000_0000));
+  for word in [0x0000, 0x3FF, 0xA55A, 0xFFFF] {
+    let raw: u32 = MsbAligned { head: 0xC3, word, low: 0x9, flags: 0x5 }.into();
+    assert_eq!(((raw & MsbAligned::WORD_MASK) >> MsbAligned::WORD_SHIFT) as u16, word);
+    assert_eq!((raw & MsbAligned::HEAD_MASK) >> MsbAligned::HEAD_SHIFT, 0xC3);
+    assert_eq!((raw & MsbAligned::LOW_MASK) >> MsbAligned::LOW_SHIFT, 0x9);
+  }
+  assert_eq!((MsbAligned::FLAGS_BITS, MsbAligned::FLAGS_SHIFT), (4, u32::MAX));
+}
+
+#[test]
+fn tuple_fields_use_indices() {
+  let _: u32 = Tuple::F0_MASK;
+  assert_eq!((Tuple::F0_SHIFT, Tuple::F0_MASK), (0, 0xFFF));
+  assert_eq!((Tuple::F1_SHIFT, Tuple::F1_MASK), (12, 0xFFF000));
+}
@@ -0,0 +1,89 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[packbits::pack(u32)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Sensor {
+  // Q7.4: 12-bit signed, 4 fractional bits
+  #[bits(12, fixed(frac = 4))]
+  temp: f32,
+  // UQ4.4 with floor rounding
+  #[bits(8, fixed(frac = 4, unsigned, round = floor))]
+  gain: f32,
+  #[bits(12, fixed(frac = 8, round = trunc))]
+  offset: f64,
+}
+
+#[test]
+fn fixed_point_roundtrip() {
+  let s = Sensor { temp: -12.5, gain: 3.75, offset: -1.25 };
+  let raw: u32 = s.into();
+  assert_eq!(raw & 0xFFF, (-200i32 as u32) & 0xFFF);
+  assert_eq!((raw >> 12) & 0xFF, 0x3C);
+  let back: Sensor = raw.into();
+  assert_eq!(back, s);
+}
+
+#[test]
+fn rounding_modes_apply_on_pack() {
+  let s = Sensor { temp: 1.03, gain: 1.99, offset: -0.0039 };
+  let back: Sensor = u32::from(s).into();
+  assert_eq!(back.temp, 1.0); // nearest: 16.48 → 16
+  assert_eq!(back.gain, 1.9375); // floor: 31.84 → 31
+  assert_eq!(back.offset, 0.0); // trunc: -0.998 → 0
+
+  let s = Sensor { temp: -1.97, gain: 0.0, offset: 0.0 };
+  let back: Sensor = u32::from(s).into();
+  assert_eq!(back.temp, -2.0); // nearest: -31.52 → -32
+}
+
+#[test]
+fn out_of_range_values_saturate_by_default() {
+  let s = Sensor { temp: 1000.0, gain: -3.0, offset: f64::NEG_INFINITY };
+  let back: Sensor = u32::from(s).into();
+  assert_eq!(back.temp, 2047.0 / 16.0);
+  assert_eq!(back.gain, 0.0);
+  assert_eq!(back.offset, -2048.0 / 256.0);
+
+  let s = Sensor { temp: f32::NAN, gain: 100.0, offset: 0.0 };
+  let back: Sensor = u32::from(s).into();
+  assert_eq!(back.temp, 0.0);
+  assert_eq!(back.gain, 255.0 / 16.0);
+}
+
+#[packbits::pack(u16)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Strict {
+  #[bits(16, fixed(frac = 15, overflow = error))]
+  q15: f32,
+}
+
+#[test]
+fn out_of_range_values_error_when_requested() {
+  let ok = Strict { q15: -0.5 };
+  let raw: u16 = ok.try_into().unwrap();
+  assert_eq!(raw, 0xC000);
+  assert_eq!(Strict::from(raw), ok);
+
+  let err = <u16 as TryFrom<Strict>>::try_from(Strict { q15: 1.0 }).unwrap_err();
+  assert_eq!(err, "pack: fixed-point value out of range in field: q15");
+  assert!(<u16 as TryFrom<Strict>>::try_from(Strict { q15: f32::NAN }).is_err());
+}
+
+#[packbits::pack(u16)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Whole {
+  #[bits(16, fixed(frac = 0))]
+  value: f64,
+}
+
+#[test]
+fn nearest_rounding_is_exact_just_below_half() {
+  // 0.49999999999999994 + 0.5 rounds up to 1.0 in f64; the value itself is below one half.
+  let below_half = 0.49999999999999994;
+  assert_eq!(u16::from(Whole { value: below_half }), 0);
+  assert_eq!(u16::from(Whole { value: -below_half }), 0);
+  assert_eq!(u16::from(Whole { value: 0.5 }), 1);
+  assert_eq!(u16::from(Whole { value: -2.5 }), (-3i16) as u16);
+}
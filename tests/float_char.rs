@@ -0,0 +1,60 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[packbits::pack(bytes = 13)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Telemetry {
+  #[bits(4)]
+  id: u8,
+  // unaligned: starts at bit 4
+  pressure: f32,
+  #[skip(4)]
+  altitude: f64,
+}
+
+#[test]
+fn floats_roundtrip_aligned_and_unaligned() {
+  let t = Telemetry { id: 0xA, pressure: -1013.25, altitude: 1.0e-3 };
+  let bytes: [u8; 13] = t.into();
+  let p = (u64::from_le_bytes(bytes[..8].try_into().unwrap()) >> 4) as u32;
+  assert_eq!(p, (-1013.25f32).to_bits());
+  assert_eq!(&bytes[5..13], &1.0e-3f64.to_le_bytes());
+  let back: Telemetry = bytes.into();
+  assert_eq!(back, t);
+}
+
+#[test]
+fn float_bit_patterns_are_preserved() {
+  let t = Telemetry { id: 0, pressure: f32::from_bits(0x7FC0_0001), altitude: -0.0 };
+  let back: Telemetry = <[u8; 13]>::from(t).into();
+  assert_eq!(back.pressure.to_bits(), 0x7FC0_0001);
+  assert_eq!(back.altitude.to_bits(), (-0.0f64).to_bits());
+}
+
+#[packbits::pack(u64)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Glyphs {
+  #[bits(21)]
+  narrow: char,
+  #[skip(3)]
+  wide: char,
+}
+
+#[test]
+fn chars_roundtrip() {
+  let g = Glyphs { narrow: '🦀', wide: 'é' };
+  let raw: u64 = g.into();
+  assert_eq!(raw & 0x1F_FFFF, '🦀' as u64);
+  assert_eq!(raw >> 24, 'é' as u64);
+  assert_eq!(Glyphs::try_from(raw), Ok(g));
+}
+
+#[test]
+fn invalid_char_is_rejected_on_unpack() {
+  let surrogate = 0xD800u64;
+  let err = Glyphs::try_from(surrogate | ('a' as u64) << 24).unwrap_err();
+  assert_eq!(err, "pack: invalid char in field: narrow");
+  let err = Glyphs::try_from(0x11_0000u64 << 24).unwrap_err();
+  assert_eq!(err, "pack: invalid char in field: wide");
+}
@@ -0,0 +1,97 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cmd {
+  Read,
+  Write,
+}
+
+impl From<Cmd> for u8 {
+  fn from(c: Cmd) -> u8 {
+    match c {
+      Cmd::Read => 1,
+      Cmd::Write => 2,
+    }
+  }
+}
+
+impl TryFrom<u8> for Cmd {
+  type Error = &'static str;
+  fn try_from(v: u8) -> Result<Self, Self::Error> {
+    match v {
+      1 => Ok(Cmd::Read),
+      2 => Ok(Cmd::Write),
+      _ => Err("invalid cmd"),
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Event {
+  Up,
+  Down,
+}
+
+impl From<Event> for u8 {
+  fn from(e: Event) -> u8 {
+    e as u8
+  }
+}
+
+impl TryFrom<u8> for Event {
+  type Error = &'static str;
+  fn try_from(v: u8) -> Result<Self, Self::Error> {
+    match v {
+      0 => Ok(Event::Up),
+      1 => Ok(Event::Down),
+      _ => Err("invalid event"),
+    }
+  }
+}
+
+#[packbits::pack(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Frame<P> {
+  #[bits(4)]
+  seq: u8,
+  #[bits(4)]
+  payload: P,
+}
+
+#[test]
+fn generic_payload_roundtrip() {
+  let f = Frame { seq: 0xA, payload: Cmd::Write };
+  let raw: u8 = f.try_into().unwrap();
+  assert_eq!(raw, 0x2A);
+  let back: Frame<Cmd> = raw.try_into().unwrap();
+  assert_eq!(back, f);
+
+  let g = Frame { seq: 3, payload: Event::Down };
+  let bytes: [u8; 1] = g.try_into().unwrap();
+  assert_eq!(bytes, [0x13]);
+  let back: Frame<Event> = bytes.try_into().unwrap();
+  assert_eq!(back, g);
+}
+
+#[test]
+fn generic_payload_error_bubbles_up() {
+  let err = <Frame<Cmd> as TryFrom<u8>>::try_from(0x70).unwrap_err();
+  assert_eq!(err, "pack: field conversion failed: P");
+}
+
+#[packbits::pack(bytes = 2)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Tagged<T: Copy, const N: usize>(#[bits(8)] T, u8)
+where
+  T: core::fmt::Debug;
+
+#[test]
+fn generic_tuple_with_where_clause() {
+  let t: Tagged<Cmd, 3> = Tagged(Cmd::Read, 0x55);
+  let bytes: [u8; 2] = t.try_into().unwrap();
+  assert_eq!(bytes, [0x01, 0x55]);
+  let back: Tagged<Cmd, 3> = bytes.try_into().unwrap();
+  assert_eq!(back, t);
+}
@@ -0,0 +1,64 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+fn source_id() -> u16 {
+  7
+}
+
+#[packbits::pack(u16)]
+#[derive(Clone, Debug, PartialEq)]
+struct Message {
+  #[bits(4)]
+  kind: u8,
+  #[pack(ignore)]
+  received_at: u64,
+  #[pack(default = source_id())]
+  source: u16,
+  #[bits(12)]
+  len: u16,
+  #[pack(ignore)]
+  note: Option<String>,
+}
+
+#[packbits::pack(bytes = 1)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Tagged(#[bits(4)] u8, #[pack(default = 0xAA)] u8, #[bits(4)] u8);
+
+#[packbits::pack(bytes = 1)]
+#[derive(Clone, Debug, PartialEq)]
+struct WithMeta<M> {
+  #[bits(8)]
+  value: u8,
+  #[pack(ignore)]
+  meta: M,
+}
+
+#[test]
+fn ignored_fields_are_not_packed() {
+  let m = Message { kind: 0x3, received_at: 123, source: 1, len: 0xABC, note: Some("local".into()) };
+  let raw: u16 = m.into();
+  assert_eq!(raw, 0xABC3);
+}
+
+#[test]
+fn ignored_fields_are_filled_on_unpack() {
+  let m: Message = 0xABC3u16.into();
+  assert_eq!(m, Message { kind: 0x3, received_at: 0, source: 7, len: 0xABC, note: None });
+}
+
+#[test]
+fn tuple_struct_ignored_fields_keep_positions() {
+  let t = Tagged(0x1, 0x55, 0x2);
+  let bytes: [u8; 1] = t.into();
+  assert_eq!(bytes, [0x21]);
+  assert_eq!(Tagged::from(bytes), Tagged(0x1, 0xAA, 0x2));
+}
+
+#[test]
+fn generic_ignored_fields_use_default() {
+  let bytes: [u8; 1] = WithMeta { value: 9, meta: vec![1, 2] }.into();
+  assert_eq!(bytes, [9]);
+  let back: WithMeta<Vec<u8>> = bytes.into();
+  assert_eq!(back, WithMeta { value: 9, meta: Vec::new() });
+}
@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+// GPIO expander port: reset and chip-select are active-low.
+#[packbits::pack(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Port {
+  #[bits(1, invert)]
+  reset: bool,
+  #[bits(1, invert)]
+  select: bool,
+  #[bits(1)]
+  led: bool,
+  #[bits(5, invert)]
+  level: u8,
+}
+
+#[test]
+fn inverted_fields_store_the_complement() {
+  let idle = Port { reset: false, select: false, led: false, level: 0 };
+  let raw: u8 = idle.into();
+  assert_eq!(raw, 0b1111_1011);
+  assert_eq!(Port::from(raw), idle);
+
+  let active = Port { reset: true, select: false, led: true, level: 0b1_0101 };
+  let raw: u8 = active.into();
+  assert_eq!(raw, 0b0101_0110);
+  assert_eq!(Port::from(raw), active);
+}
+
+#[packbits::pack(bytes = 2, msb)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Wide(#[bits(12, invert)] u16, #[bits(4, invert, none = 0xF)] Option<u8>);
+
+#[test]
+fn inverted_fields_across_bytes_and_with_sentinel() {
+  let w = Wide(0x123, Some(2));
+  let bytes: [u8; 2] = w.try_into().unwrap();
+  assert_eq!(bytes, [0xDC, 0xED]);
+  assert_eq!(Wide::from(bytes), w);
+
+  // The sentinel names the stored bits: `None` is all ones on the wire.
+  let n = Wide(0xFFF, None);
+  let bytes: [u8; 2] = n.try_into().unwrap();
+  assert_eq!(bytes, [0x00, 0x0F]);
+  assert_eq!(Wide::from(bytes), n);
+  assert!(<[u8; 2]>::try_from(Wide(0, Some(0))).is_err());
+}
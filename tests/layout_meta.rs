@@ -0,0 +1,62 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Mode(u8);
+
+impl TryFrom<u8> for Mode {
+  type Error = &'static str;
+  fn try_from(v: u8) -> Result<Self, Self::Error> {
+    Ok(Mode(v))
+  }
+}
+
+impl TryFrom<Mode> for u8 {
+  type Error = &'static str;
+  fn try_from(m: Mode) -> Result<Self, Self::Error> {
+    Ok(m.0)
+  }
+}
+
+#[packbits::pack(bytes = 4, msb)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Frame {
+  flag: bool,
+  #[bits(5)]
+  delta: i8,
+  #[skip(2)]
+  #[bits(8, fixed(frac = 4))]
+  temp: f32,
+  #[bits(3)]
+  mode: Mode,
+  #[bits(13)]
+  len: u16,
+}
+
+#[packbits::pack(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Tiny(#[bits(4)] u8, #[bits(4)] u8);
+
+#[test]
+fn layout_describes_every_packed_field() {
+  assert_eq!(
+    Frame::LAYOUT,
+    &[
+      ("flag", 0, 1, "bool", false),
+      ("delta", 1, 5, "int", true),
+      ("temp", 8, 8, "fixed", true),
+      ("mode", 16, 3, "custom", false),
+      ("len", 19, 13, "int", false),
+    ]
+  );
+  assert_eq!(Frame::LAYOUT_BYTES, 4);
+  const _: () = assert!(Frame::LAYOUT_MSB);
+}
+
+#[test]
+fn tuple_layout_uses_indices() {
+  assert_eq!(Tiny::LAYOUT, &[("0", 0, 4, "int", false), ("1", 4, 4, "int", false)]);
+  assert_eq!(Tiny::LAYOUT_BYTES, 1);
+  const _: () = assert!(!Tiny::LAYOUT_MSB);
+}
@@ -0,0 +1,71 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[packbits::pack(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Power {
+  // temperature = raw - 40 (°C)
+  #[bits(8, offset = -40)]
+  temp: i16,
+  // voltage = raw * 25 (mV)
+  #[bits(10, scale = 25)]
+  millivolts: u16,
+  // current = raw * 4 - 100 (mA), raw is two's complement
+  #[bits(8, scale = 4, offset = -100, signed)]
+  milliamps: i16,
+}
+
+#[test]
+fn affine_transform_roundtrip() {
+  let p = Power { temp: -40, millivolts: 3300, milliamps: -104 };
+  let raw: u32 = p.into();
+  assert_eq!(raw & 0xFF, 0);
+  assert_eq!((raw >> 8) & 0x3FF, 132);
+  assert_eq!((raw >> 18) & 0xFF, 0xFF); // (-104 + 100) / 4 = -1
+  assert_eq!(Power::from(raw), p);
+
+  let hot: Power = (0xFFu32 | (1023 << 8)).into();
+  assert_eq!(hot.temp, 215);
+  assert_eq!(hot.millivolts, 25_575);
+}
+
+#[test]
+fn inexact_values_round_down() {
+  let p = Power { temp: 0, millivolts: 3310, milliamps: -99 };
+  let back: Power = u32::from(p).into();
+  assert_eq!(back.millivolts, 3300);
+  assert_eq!(back.milliamps, -100);
+}
+
+#[test]
+fn out_of_range_values_saturate() {
+  let cold = Power { temp: -50, millivolts: 30_000, milliamps: -1000 };
+  let back: Power = u32::from(cold).into();
+  assert_eq!(back, Power { temp: -40, millivolts: 25_575, milliamps: -612 });
+
+  let hot: Power = u32::from(Power { temp: 300, millivolts: 0, milliamps: 1000 }).into();
+  assert_eq!((hot.temp, hot.milliamps), (215, 408));
+}
+
+#[packbits::pack(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Strict {
+  #[bits(8, scale = 5, offset = 10, strict)]
+  level: u16,
+  #[bits(8)]
+  pad: u8,
+}
+
+#[test]
+fn strict_rejects_unrepresentable_values() {
+  let ok = Strict { level: 1285, pad: 0 };
+  let raw: u16 = ok.try_into().unwrap();
+  assert_eq!(raw, 255);
+  assert_eq!(Strict::from(raw), ok);
+
+  let err = <u16 as TryFrom<Strict>>::try_from(Strict { level: 12, pad: 0 }).unwrap_err();
+  assert_eq!(err, "pack: value not representable in field: level");
+  assert!(<u16 as TryFrom<Strict>>::try_from(Strict { level: 5, pad: 0 }).is_err());
+  assert!(<u16 as TryFrom<Strict>>::try_from(Strict { level: 1290, pad: 0 }).is_err());
+}
@@ -0,0 +1,57 @@
+#![allow(dead_code)]
+
+use core::num::{NonZero, NonZeroI8, NonZeroU8, NonZeroU16, NonZeroU32};
+
+use packbits as _;
+
+#[packbits::pack(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Clock {
+  #[bits(4)]
+  divider: NonZeroU8,
+  #[bits(4)]
+  mode: u8,
+  prescale: core::num::NonZeroU16,
+  #[bits(5)]
+  trim: NonZeroI8,
+  #[bits(3)]
+  pad: u8,
+}
+
+#[test]
+fn roundtrip_nonzero_fields() {
+  let c = Clock {
+    divider: NonZeroU8::new(9).unwrap(),
+    mode: 3,
+    prescale: NonZeroU16::new(0x1234).unwrap(),
+    trim: NonZeroI8::new(-5).unwrap(),
+    pad: 0,
+  };
+  // Packing is infallible: a NonZero value is always a valid integer.
+  let raw: u32 = c.into();
+  assert_eq!(raw & 0xFF, 0x39);
+  assert_eq!((raw >> 8) & 0xFFFF, 0x1234);
+  let back: Clock = raw.try_into().unwrap();
+  assert_eq!(back, c);
+}
+
+#[test]
+fn zero_value_is_rejected_on_unpack() {
+  let err = Clock::try_from([0x30, 0x34, 0x12, 0x01]).unwrap_err();
+  assert_eq!(err, "pack: zero value in non-zero field: divider");
+  let err = Clock::try_from(0x0000_0031u32).unwrap_err();
+  assert_eq!(err, "pack: zero value in non-zero field: prescale");
+}
+
+#[packbits::pack(bytes = 4)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Generic(NonZero<u32>);
+
+#[test]
+fn nonzero_generic_form_is_inferred() {
+  let g = Generic(NonZeroU32::new(0xDEAD_BEEF).unwrap());
+  let bytes: [u8; 4] = g.into();
+  assert_eq!(bytes, 0xDEAD_BEEFu32.to_le_bytes());
+  assert_eq!(Generic::try_from(bytes), Ok(g));
+  assert!(Generic::try_from([0u8; 4]).is_err());
+}
@@ -0,0 +1,84 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[packbits::pack(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Reading {
+  #[bits(11, none = 0x7FF)]
+  temp: Option<u16>,
+  #[bits(5)]
+  channel: u8,
+}
+
+#[test]
+fn sentinel_maps_to_none() {
+  let r = Reading { temp: None, channel: 4 };
+  let raw: u16 = r.try_into().unwrap();
+  assert_eq!(raw, 0x7FF | (4 << 11));
+  let back: Reading = raw.into();
+  assert_eq!(back, r);
+}
+
+#[test]
+fn some_value_roundtrips() {
+  let r = Reading { temp: Some(0x123), channel: 31 };
+  let bytes: [u8; 2] = r.try_into().unwrap();
+  assert_eq!(u16::from_le_bytes(bytes), 0x123 | (31 << 11));
+  let back: Reading = bytes.into();
+  assert_eq!(back, r);
+}
+
+#[test]
+fn some_sentinel_is_rejected_on_pack() {
+  let r = Reading { temp: Some(0x7FF), channel: 0 };
+  let err = <u16 as TryFrom<Reading>>::try_from(r).unwrap_err();
+  assert_eq!(err, "pack: value reserved for None in field: temp");
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Level {
+  Low = 1,
+  High = 2,
+}
+
+impl From<Level> for u8 {
+  fn from(l: Level) -> u8 {
+    l as u8
+  }
+}
+
+impl TryFrom<u8> for Level {
+  type Error = ();
+  fn try_from(v: u8) -> Result<Self, ()> {
+    match v {
+      1 => Ok(Level::Low),
+      2 => Ok(Level::High),
+      _ => Err(()),
+    }
+  }
+}
+
+#[packbits::pack(bytes = 3)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Mixed(
+  #[bits(2, none = 0)] Option<Level>,
+  #[skip(6)]
+  #[bits(none = 0xFFFF)]
+  Option<i16>,
+);
+
+#[test]
+fn sentinel_on_custom_and_inferred_width() {
+  let m = Mixed(Some(Level::High), Some(-2));
+  let bytes: [u8; 3] = m.try_into().unwrap();
+  assert_eq!(bytes, [0x02, 0xFE, 0xFF]);
+  assert_eq!(Mixed::try_from(bytes), Ok(m));
+
+  let n = Mixed(None, None);
+  let bytes: [u8; 3] = n.try_into().unwrap();
+  assert_eq!(bytes, [0x00, 0xFF, 0xFF]);
+  assert_eq!(Mixed::try_from(bytes), Ok(n));
+
+  assert!(<[u8; 3]>::try_from(Mixed(None, Some(-1))).is_err());
+}
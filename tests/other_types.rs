@@ -163,3 +163,24 @@ fn msb_order_other_bits_positioning() {
   let back = MsbOther::try_from(arr).unwrap();
   assert_eq!(back, s);
 }
+
+// `Tri` converts with `From` both ways, so the struct can keep infallible conversions.
+#[packbits::pack(u8, msb)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct InfallibleOther {
+  #[bits(3, infallible)]
+  t: Tri,
+  #[bits(5)]
+  lo: u8,
+}
+
+#[test]
+fn infallible_other_type_uses_from() {
+  let s = InfallibleOther { t: Tri::O, lo: 0b0_0111 };
+  let raw: u8 = s.into();
+  assert_eq!(raw, (1u8 << 5) | 0b0_0111);
+  let back: InfallibleOther = raw.into();
+  assert_eq!(back, s);
+  let arr: [u8; 1] = s.into();
+  assert_eq!(InfallibleOther::from(arr), s);
+}
@@ -0,0 +1,48 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+type Reg = u16;
+type Level = i8;
+
+#[packbits::pack(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Qualified {
+  ready: core::primitive::bool,
+  #[bits(7)]
+  count: std::primitive::u8,
+  value: ::core::primitive::i16,
+  #[bits(8)]
+  tail: core::primitive::u8,
+}
+
+#[packbits::pack(bytes = 5)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Aliased {
+  #[bits(12, int)]
+  reg: Reg,
+  #[bits(int = i8)]
+  level: Level,
+  #[bits(int = u16)]
+  full: Reg,
+  #[bits(4)]
+  rest: u8,
+}
+
+#[test]
+fn qualified_primitive_paths_infer_width_and_stay_infallible() {
+  let q = Qualified { ready: true, count: 0x55, value: -2, tail: 0xA5 };
+  let raw: u32 = q.into();
+  assert_eq!(raw, 0xA5FF_FEAB);
+  let back: Qualified = raw.into();
+  assert_eq!(back, q);
+}
+
+#[test]
+fn int_declares_aliases_as_primitive() {
+  let a = Aliased { reg: 0xABC, level: -3, full: 0x1234, rest: 0x9 };
+  let bytes: [u8; 5] = a.into();
+  assert_eq!(bytes, [0xBC, 0xDA, 0x4F, 0x23, 0x91]);
+  let back: Aliased = bytes.into();
+  assert_eq!(back, a);
+}
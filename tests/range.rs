@@ -0,0 +1,62 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[packbits::pack(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Clock {
+  #[bits(4, range = 0..=11)]
+  month: u8,
+  #[bits(6, range = -20..20)]
+  trim: i8,
+  #[bits(6)]
+  free: u8,
+}
+
+#[packbits::pack(bytes = 1)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Optional {
+  #[bits(4, none = 0xF, range = 1..=9)]
+  level: Option<u8>,
+  #[bits(4)]
+  rest: u8,
+}
+
+#[test]
+fn in_range_values_round_trip() {
+  let c = Clock { month: 11, trim: -20, free: 0x3F };
+  let raw: u16 = c.try_into().unwrap();
+  assert_eq!(raw, 0xFECB);
+  assert_eq!(raw & 0xF, 11);
+  let back: Clock = raw.try_into().unwrap();
+  assert_eq!(back, c);
+}
+
+#[test]
+fn out_of_range_values_are_rejected_on_pack() {
+  let c = Clock { month: 12, trim: 0, free: 0 };
+  let err = <u16 as TryFrom<Clock>>::try_from(c).unwrap_err();
+  assert_eq!(err, "pack: value out of range in field: month");
+  let c = Clock { month: 0, trim: 20, free: 0 };
+  let err = <[u8; 2] as TryFrom<Clock>>::try_from(c).unwrap_err();
+  assert_eq!(err, "pack: value out of range in field: trim");
+}
+
+#[test]
+fn out_of_range_values_are_rejected_on_unpack() {
+  let err = Clock::try_from(0x000Cu16).unwrap_err();
+  assert_eq!(err, "pack: value out of range in field: month");
+  // trim = -21 (0b101011)
+  let err = Clock::try_from(0x2B0u16).unwrap_err();
+  assert_eq!(err, "pack: value out of range in field: trim");
+}
+
+#[test]
+fn range_applies_to_some_values_only() {
+  let bytes: [u8; 1] = Optional { level: None, rest: 0 }.try_into().unwrap();
+  assert_eq!(bytes, [0x0F]);
+  assert_eq!(Optional::try_from([0x0F]).unwrap().level, None);
+  assert_eq!(Optional::try_from([0x05]).unwrap().level, Some(5));
+  assert!(Optional::try_from([0x00]).is_err());
+  assert!(<[u8; 1]>::try_from(Optional { level: Some(0), rest: 0 }).is_err());
+}
@@ -0,0 +1,50 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[packbits::pack(u32, raw = HeaderBits)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Header {
+  #[bits(4)]
+  ver: u8,
+  #[bits(12)]
+  len: u16,
+  #[bits(5, range = 0..=20)]
+  prio: u8,
+  ack: bool,
+}
+
+#[packbits::pack(bytes = 2, raw = PairBits)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Pair(#[bits(8)] u8, #[bits(8)] i8);
+
+#[test]
+fn getters_and_setters_work_on_the_raw_value() {
+  let mut raw = HeaderBits(0);
+  raw.set_ver(0x5);
+  raw.set_len(0xABC);
+  raw.set_prio(7).unwrap();
+  let raw = raw.with_ack(true);
+  assert_eq!((raw.ver(), raw.len(), raw.prio(), raw.ack()), (0x5, 0xABC, Ok(7), true));
+  assert_eq!(raw.0, 0x5 | 0xABC << 4 | 7 << 16 | 1 << 21);
+  assert!(raw.with_prio(21).is_err());
+}
+
+#[test]
+fn converts_to_and_from_the_struct() {
+  let h = Header { ver: 0x1, len: 0x234, prio: 20, ack: false };
+  let raw = HeaderBits::try_from(h).unwrap();
+  assert_eq!(raw.0, u32::try_from(h).unwrap());
+  assert_eq!(Header::try_from(raw), Ok(h));
+  assert_eq!(u32::from(raw), raw.0);
+  assert_eq!(HeaderBits::from(raw.0), raw);
+  assert_eq!(core::mem::size_of::<HeaderBits>(), 4);
+}
+
+#[test]
+fn byte_array_raw_and_tuple_fields() {
+  let raw = PairBits::from(Pair(0x12, -3));
+  assert_eq!(raw.0, [0x12, 0xFD]);
+  assert_eq!((raw.f0(), raw.f1()), (0x12, -3));
+  assert_eq!(Pair::from(raw.with_f1(4)), Pair(0x12, 4));
+}
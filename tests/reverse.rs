@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+// NEC-style IR frame: address and command are sent LSB first.
+#[packbits::pack(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct IrFrame {
+  #[bits(8, reverse)]
+  address: u8,
+  #[bits(8, reverse, invert)]
+  address_inv: u8,
+  #[bits(10, reverse)]
+  command: u16,
+  #[bits(6)]
+  tail: u8,
+}
+
+#[test]
+fn reversed_fields_roundtrip() {
+  let f = IrFrame { address: 0b0000_0001, address_inv: 0b0000_0001, command: 0b00_0000_0011, tail: 0b10_1010 };
+  let raw: u32 = f.into();
+  assert_eq!(raw & 0xFF, 0b1000_0000);
+  assert_eq!((raw >> 8) & 0xFF, 0b0111_1111);
+  assert_eq!((raw >> 16) & 0x3FF, 0b11_0000_0000);
+  assert_eq!(raw >> 26, 0b10_1010);
+  assert_eq!(IrFrame::from(raw), f);
+}
+
+#[packbits::pack(bytes = 1, msb)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Nibbles(#[bits(3, reverse)] u8, #[bits(5)] u8);
+
+#[test]
+fn reverse_is_independent_of_bit_order() {
+  // 0b001 reversed within 3 bits is 0b100; msb order places it in the top bits.
+  let n = Nibbles(0b001, 0);
+  let bytes: [u8; 1] = n.into();
+  assert_eq!(bytes, [0b1000_0000]);
+  assert_eq!(Nibbles::from(bytes), n);
+}
@@ -0,0 +1,165 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[packbits::pack(bytes = 3)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Record {
+  #[bits(4)]
+  kind: u8,
+  #[bits(12)]
+  len: u16,
+  #[bits(8)]
+  seq: u8,
+}
+
+#[packbits::pack(u8)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Level {
+  #[bits(4, range = 0..=9)]
+  level: u8,
+  #[bits(4)]
+  rest: u8,
+}
+
+fn records() -> Vec<Record> {
+  (0..5u16)
+    .map(|i| Record { kind: i as u8, len: 0x100 + i, seq: 0xF0 | i as u8 })
+    .collect()
+}
+
+#[test]
+fn pack_and_unpack_slices_round_trip() {
+  let items = records();
+  let mut bytes = vec![0u8; items.len() * Record::LAYOUT_BYTES];
+  Record::pack_slice(&items, &mut bytes).unwrap();
+  for (item, chunk) in items.iter().zip(bytes.chunks_exact(3)) {
+    assert_eq!(chunk, <[u8; 3]>::from(*item));
+  }
+  let mut back = vec![Record::default(); items.len()];
+  Record::unpack_slice(&bytes, &mut back).unwrap();
+  assert_eq!(back, items);
+}
+
+#[test]
+fn length_mismatches_are_rejected() {
+  let items = records();
+  assert!(Record::pack_slice(&items, &mut [0u8; 14]).is_err());
+  assert!(Record::unpack_slice(&[0u8; 14], &mut [Record::default(); 5]).is_err());
+}
+
+#[test]
+fn unpack_iter_walks_whole_records() {
+  let items = records();
+  let mut bytes = vec![0u8; 15];
+  Record::pack_slice(&items, &mut bytes).unwrap();
+  bytes.push(0xAA);
+  assert_eq!(Record::unpack_iter(&bytes).collect::<Vec<_>>(), items);
+}
+
+#[test]
+fn fallible_records_report_errors() {
+  let mut out = [0u8; 2];
+  assert!(Level::pack_slice(&[Level { level: 3, rest: 1 }, Level { level: 10, rest: 0 }], &mut out).is_err());
+  let mut levels = [Level::default(); 2];
+  assert!(Level::unpack_slice(&[0x13, 0x0A], &mut levels).is_err());
+  let decoded: Vec<_> = Level::unpack_iter(&[0x13, 0x0A]).collect();
+  assert_eq!(
+    decoded,
+    [
+      Ok(Level { level: 3, rest: 1 }),
+      Err("pack: value out of range in field: level")
+    ]
+  );
+}
+
+// Neither `Clone` nor `Copy`: `pack_slice` must still compile alongside it, and `with` fields
+// are packed by reference.
+#[derive(Debug, PartialEq, Eq)]
+struct Tag(u8);
+
+mod tag {
+  pub fn pack(t: &super::Tag) -> Result<u8, &'static str> {
+    Ok(t.0)
+  }
+  pub fn unpack(raw: u8) -> Result<super::Tag, &'static str> {
+    Ok(super::Tag(raw))
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Unit(u8);
+
+impl TryFrom<u8> for Unit {
+  type Error = &'static str;
+  fn try_from(v: u8) -> Result<Self, Self::Error> {
+    Ok(Unit(v))
+  }
+}
+
+impl TryFrom<Unit> for u8 {
+  type Error = &'static str;
+  fn try_from(u: Unit) -> Result<Self, Self::Error> {
+    Ok(u.0)
+  }
+}
+
+#[packbits::pack(bytes = 2)]
+#[derive(Debug, PartialEq, Eq)]
+struct Tagged {
+  #[bits(8, with = tag)]
+  tag: Tag,
+  #[bits(4)]
+  unit: Unit,
+  #[bits(4)]
+  chunk: u8,
+}
+
+impl TryFrom<u8> for Tag {
+  type Error = &'static str;
+  fn try_from(v: u8) -> Result<Self, Self::Error> {
+    Ok(Tag(v))
+  }
+}
+
+impl TryFrom<Tag> for u8 {
+  type Error = &'static str;
+  fn try_from(t: Tag) -> Result<Self, Self::Error> {
+    Ok(t.0)
+  }
+}
+
+// `pack_slice` needs `Tag: Clone` here, which is only checked where it is called.
+#[packbits::pack(u8)]
+struct Owned {
+  #[bits(8)]
+  tag: Tag,
+}
+
+#[test]
+fn records_are_packed_by_reference() {
+  let items = [
+    Tagged { tag: Tag(7), unit: Unit(2), chunk: 9 },
+    Tagged { tag: Tag(0xAB), unit: Unit(15), chunk: 0 },
+  ];
+  let mut bytes = [0u8; 4];
+  Tagged::pack_slice(&items, &mut bytes).unwrap();
+  assert_eq!(bytes, [0x07, 0x92, 0xAB, 0x0F]);
+  let back: Vec<_> = Tagged::unpack_iter(&bytes).map(Result::unwrap).collect();
+  assert_eq!(back, items);
+  assert_eq!(Owned::try_from(0x42u8).unwrap().tag, Tag(0x42));
+}
+
+#[packbits::pack(bytes = 0)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Marker {}
+
+#[test]
+fn zero_sized_records_do_not_panic() {
+  Marker::pack_slice(&[Marker {}; 3], &mut []).unwrap();
+  assert!(Marker::pack_slice(&[Marker {}], &mut [0u8]).is_err());
+  let mut out = [Marker {}; 2];
+  Marker::unpack_slice(&[], &mut out).unwrap();
+  assert!(Marker::unpack_slice(&[0u8], &mut out).is_err());
+  assert_eq!(Marker::unpack_iter(&[1, 2, 3]).count(), 0);
+}
@@ -0,0 +1,9 @@
+use packbits as _;
+
+#[packbits::pack(bytes = 1)]
+struct Bad {
+  #[bits(8, bcd)]
+  value: i8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use packbits as _;
+
+struct Wide(u16);
+
+#[packbits::pack(bytes = 2)]
+struct Bad {
+  #[bits(12, carrier = u8)]
+  w: Wide,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use packbits as _;
+
+#[packbits::pack(bytes = 1)]
+struct Bad {
+  #[bits(4)]
+  a: u8,
+  #[cfg(all())]
+  #[bits(8)]
+  b: u8,
+  #[cfg(any())]
+  #[bits(8)]
+  c: u16,
+}
+
+fn main() {}
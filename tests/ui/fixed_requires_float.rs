@@ -0,0 +1,9 @@
+use packbits as _;
+
+#[packbits::pack(bytes = 2)]
+struct Bad {
+  #[bits(12, fixed(frac = 4))]
+  temp: i16,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use packbits as _;
+
+#[packbits::pack(bytes = 4)]
+struct Bad {
+  #[bits(16)]
+  x: f32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use packbits as _;
+
+#[packbits::pack(bytes = 1)]
+struct Bad {
+  #[bits(4)]
+  #[pack(ignore)]
+  a: u8,
+}
+
+fn main() {}
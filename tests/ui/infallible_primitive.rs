@@ -0,0 +1,9 @@
+use packbits as _;
+
+#[packbits::pack(bytes = 1)]
+struct Bad {
+  #[bits(4, infallible)]
+  a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use packbits as _;
+
+type Reg = u16;
+
+#[packbits::pack(bytes = 2)]
+struct Bad {
+  #[bits(int)]
+  reg: Reg,
+}
+
+fn main() {}
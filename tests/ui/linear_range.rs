@@ -0,0 +1,9 @@
+use packbits as _;
+
+#[packbits::pack(bytes = 1)]
+struct Bad {
+  #[bits(8, offset = -40)]
+  temp: u8,
+}
+
+fn main() {}
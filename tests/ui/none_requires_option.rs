@@ -0,0 +1,9 @@
+use packbits as _;
+
+#[packbits::pack(bytes = 2)]
+struct Bad {
+  #[bits(11, none = 0x7FF)]
+  temp: u16,
+}
+
+fn main() {}
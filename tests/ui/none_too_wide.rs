@@ -0,0 +1,9 @@
+use packbits as _;
+
+#[packbits::pack(bytes = 1)]
+struct Bad {
+  #[bits(4, none = 0x1F)]
+  temp: Option<u8>,
+}
+
+fn main() {}
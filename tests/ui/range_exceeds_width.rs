@@ -0,0 +1,9 @@
+use packbits as _;
+
+#[packbits::pack(bytes = 1)]
+struct Bad {
+  #[bits(4, range = 0..=20)]
+  a: u8,
+}
+
+fn main() {}
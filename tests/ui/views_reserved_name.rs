@@ -0,0 +1,11 @@
+use packbits as _;
+
+#[packbits::pack(u8, views)]
+struct V {
+  #[bits(4)]
+  view: u8,
+  #[bits(4)]
+  rest: u8,
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[packbits::pack(bytes = 4, views)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Header {
+  #[bits(4)]
+  ver: u8,
+  #[bits(12)]
+  len: u16,
+  #[bits(8, none = 0xFF)]
+  hop: Option<u8>,
+  #[bits(7)]
+  ttl: u8,
+  urgent: bool,
+}
+
+#[test]
+fn views_read_fields_in_a_buffer() {
+  let h = Header { ver: 0x4, len: 0x123, hop: Some(9), ttl: 64, urgent: true };
+  let bytes: [u8; 4] = h.try_into().unwrap();
+  let mut buffer = [0u8; 12];
+  buffer[4..8].copy_from_slice(&bytes);
+
+  let view = HeaderRef::try_from(&buffer[4..8]).unwrap();
+  assert_eq!((view.ver(), view.len(), view.hop(), view.ttl(), view.urgent()), (0x4, 0x123, Some(9), 64, true));
+  assert_eq!(view.as_bytes(), &bytes);
+  assert!(HeaderRef::try_from(&buffer[4..7]).is_err());
+}
+
+#[test]
+fn mutable_views_patch_in_place() {
+  let h = Header { ver: 0x4, len: 0x123, hop: Some(9), ttl: 64, urgent: false };
+  let mut buffer = [0xEEu8; 8];
+  buffer[..4].copy_from_slice(&<[u8; 4]>::try_from(h).unwrap());
+
+  let mut view = HeaderMut::try_from(&mut buffer[..4]).unwrap();
+  view.set_ttl(view.ttl() - 1);
+  view.set_hop(None).unwrap();
+  assert!(view.set_hop(Some(0xFF)).is_err());
+  assert_eq!(view.view().ttl(), 63);
+
+  let patched: [u8; 4] = buffer[..4].try_into().unwrap();
+  assert_eq!(Header::from(patched), Header { ttl: 63, hop: None, ..h });
+  assert_eq!(buffer[4..], [0xEE; 4]);
+  assert!(HeaderMut::try_from(&mut buffer[..]).is_err());
+}
@@ -0,0 +1,92 @@
+#![allow(dead_code)]
+
+use packbits as _;
+
+#[packbits::pack(u32, volatile)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Ctrl {
+  #[bits(4)]
+  ver: u8,
+  enable: bool,
+  #[bits(11)]
+  len: u16,
+  #[bits(8, range = 0..=100)]
+  duty: u8,
+}
+
+#[packbits::pack(bytes = 3, volatile)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Wide {
+  #[bits(12)]
+  a: u16,
+  #[bits(12)]
+  b: u16,
+}
+
+#[test]
+fn modify_updates_a_register_in_memory() {
+  let mut reg = 0u32;
+  let ptr = &mut reg as *mut u32;
+  let c = Ctrl { ver: 1, enable: false, len: 0x123, duty: 50 };
+  unsafe {
+    Ctrl::write_volatile(ptr, c).unwrap();
+    Ctrl::modify(ptr, |r| {
+      r.ver = 3;
+      r.enable = true;
+    })
+    .unwrap();
+    assert_eq!(Ctrl::read_volatile(ptr), Ok(Ctrl { ver: 3, enable: true, ..c }));
+  }
+  assert_eq!(reg, u32::try_from(Ctrl { ver: 3, enable: true, ..c }).unwrap());
+}
+
+#[test]
+fn modify_field_touches_only_that_field() {
+  let mut reg = 0xFFFF_FFFFu32;
+  let ptr = &mut reg as *mut u32;
+  unsafe {
+    Ctrl::modify_len(ptr, 0);
+    Ctrl::modify_ver(ptr, 0x2);
+    assert!(Ctrl::modify_duty(ptr, 101).is_err());
+  }
+  assert_eq!(reg, !(0x7FF << 5) & !0xF | 0x2);
+}
+
+#[test]
+fn byte_array_registers() {
+  let mut reg = [0u8; 3];
+  let ptr = &mut reg as *mut [u8; 3];
+  unsafe {
+    Wide::write_volatile(ptr, Wide { a: 0xABC, b: 0x123 });
+    Wide::modify_b(ptr, 0x456);
+    assert_eq!(Wide::read_volatile(ptr), Wide { a: 0xABC, b: 0x456 });
+  }
+  assert_eq!(reg, [0xBC, 0x6A, 0x45]);
+}
+
+// Without `volatile`, the helper names stay free for the struct's own API.
+#[packbits::pack(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Plain {
+  #[bits(4)]
+  volatile: u8,
+  #[bits(4)]
+  rest: u8,
+}
+
+impl Plain {
+  fn modify(&mut self) {
+    self.rest += 1;
+  }
+
+  fn read_volatile(&self) -> u8 {
+    self.volatile
+  }
+}
+
+#[test]
+fn helpers_are_opt_in() {
+  let mut p = Plain::from(0x21u8);
+  p.modify();
+  assert_eq!((p.read_volatile(), u8::from(p)), (1, 0x31));
+}
@@ -0,0 +1,66 @@
+#![allow(dead_code)]
+
+use core::time::Duration;
+
+use packbits as _;
+
+// `Duration` is foreign, so `TryFrom<u16> for Duration` cannot be implemented here.
+mod millis {
+  use core::time::Duration;
+
+  pub fn pack(d: &Duration) -> Result<u16, &'static str> {
+    u16::try_from(d.as_millis()).map_err(|_| "too long")
+  }
+
+  pub fn unpack(raw: u16) -> Result<Duration, &'static str> {
+    Ok(Duration::from_millis(raw as u64))
+  }
+}
+
+mod tenths {
+  use core::time::Duration;
+
+  pub fn pack(d: &Duration) -> Result<u8, ()> {
+    if d.subsec_millis() % 100 != 0 {
+      Err(())
+    } else {
+      Ok((d.as_millis() / 100) as u8)
+    }
+  }
+
+  pub fn unpack(raw: u8) -> Result<Duration, ()> {
+    if raw > 50 {
+      Err(())
+    } else {
+      Ok(Duration::from_millis(raw as u64 * 100))
+    }
+  }
+}
+
+#[packbits::pack(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Timer {
+  #[bits(12, with = millis)]
+  timeout: Duration,
+  #[bits(6, with = self::tenths)]
+  debounce: Duration,
+  #[bits(14)]
+  id: u16,
+}
+
+#[test]
+fn with_module_roundtrip() {
+  let t = Timer { timeout: Duration::from_millis(1500), debounce: Duration::from_millis(300), id: 7 };
+  let raw: u32 = t.try_into().unwrap();
+  assert_eq!(raw & 0xFFF, 1500);
+  assert_eq!((raw >> 12) & 0x3F, 3);
+  assert_eq!(Timer::try_from(raw), Ok(t));
+}
+
+#[test]
+fn with_module_errors_bubble_up() {
+  let t = Timer { timeout: Duration::from_millis(1500), debounce: Duration::from_millis(250), id: 0 };
+  let err = <u32 as TryFrom<Timer>>::try_from(t).unwrap_err();
+  assert_eq!(err, "pack: field conversion failed: Duration");
+  assert!(Timer::try_from(63u32 << 12).is_err());
+}